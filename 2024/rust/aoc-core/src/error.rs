@@ -0,0 +1,80 @@
+use std::fmt;
+
+const SNIPPET_LENGTH: usize = 16;
+
+/// Malformed puzzle input, located by 1-based line and column
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, snippet: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            snippet: snippet.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error found where `rest` starts, `rest` being a slice of `input`
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        let snippet: String = input[offset..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(SNIPPET_LENGTH)
+            .collect();
+        Self::new(line, column, snippet, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: expected {}", self.line, self.column, self.expected)?;
+        if self.snippet.is_empty() {
+            write!(f, ", found end of line")
+        } else {
+            write!(f, ", found \"{}\"", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_rest_of_input() {
+        let input = "12 34\n56 7x\n";
+        let e = ParseError::at(input, &input[10..], "digit");
+        assert_eq!(e, ParseError::new(2, 5, "x", "digit"));
+    }
+
+    #[test]
+    fn locate_end_of_input() {
+        let input = "12 34\n";
+        let e = ParseError::at(input, &input[input.len()..], "digit");
+        assert_eq!(e, ParseError::new(2, 1, "", "digit"));
+    }
+
+    #[test]
+    fn display_location() {
+        let e = ParseError::new(12, 5, "; 3", "','");
+        assert_eq!(e.to_string(), "12:5: expected ',', found \"; 3\"");
+    }
+}
@@ -0,0 +1,8 @@
+mod error;
+mod options;
+pub mod parser;
+mod solution;
+
+pub use error::ParseError;
+pub use options::Options;
+pub use solution::Solution;
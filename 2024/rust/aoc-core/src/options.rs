@@ -0,0 +1,59 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+/// Puzzle settings given on the command line as `key=value`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Options(HashMap<String, String>);
+
+impl Options {
+    /// Splits a `key=value` argument
+    pub fn pair(arg: &str) -> Result<(String, String), String> {
+        arg.split_once('=')
+            .filter(|(key, _)| !key.is_empty())
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .ok_or(format!("Expected key=value, found \"{arg}\""))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Value of `key` parsed as `T`, `None` when not given
+    pub fn get<T>(&self, key: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.0
+            .get(key)
+            .map(|value| value
+                .parse()
+                .map_err(|e| format!("Invalid {key} \"{value}\": {e}")))
+            .transpose()
+    }
+}
+
+impl FromIterator<(String, String)> for Options {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_pair() {
+        assert_eq!(Options::pair("size=11"), Ok(("size".to_string(), "11".to_string())));
+        assert!(Options::pair("size").is_err());
+        assert!(Options::pair("=11").is_err());
+    }
+
+    #[test]
+    fn typed_value() {
+        let options: Options = [Options::pair("size=11").expect("Expecting a pair")].into_iter().collect();
+        assert_eq!(options.get::<usize>("size"), Ok(Some(11)));
+        assert_eq!(options.get::<usize>("limit"), Ok(None));
+        assert!(options.get::<bool>("size").is_err());
+    }
+}
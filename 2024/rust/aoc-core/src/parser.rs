@@ -0,0 +1,144 @@
+use std::fmt;
+
+use nom::{
+    error::{ContextError, ErrorKind, FromExternalError},
+    Parser,
+};
+
+use crate::ParseError;
+
+/// What a nom parser was expecting when it failed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expected {
+    Kind(ErrorKind),
+    Char(char),
+    Tag(&'static str),
+    Context(&'static str),
+}
+
+/// nom error keeping what was expected, to be located later against the whole input
+#[derive(Debug, PartialEq)]
+pub struct Error<'a> {
+    pub input: &'a str,
+    pub expected: Expected,
+}
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Kind(ErrorKind::Digit) => write!(f, "digit"),
+            Expected::Kind(ErrorKind::CrLf) => write!(f, "line ending"),
+            Expected::Kind(ErrorKind::Eof) => write!(f, "end of input"),
+            Expected::Kind(ErrorKind::MapRes) => write!(f, "number"),
+            Expected::Kind(kind) => write!(f, "{}", kind.description().to_lowercase()),
+            Expected::Char(c) => write!(f, "'{}'", c.escape_default()),
+            Expected::Tag(t) => write!(f, "'{}'", t.escape_default()),
+            Expected::Context(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self { input, expected: Expected::Kind(kind) }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self { input, expected: Expected::Char(c) }
+    }
+
+    fn or(self, other: Self) -> Self {
+        if other.input.len() <= self.input.len() { other } else { self }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() {
+            Self { input, expected: Expected::Context(ctx) }
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        Self { input, expected: Expected::Kind(kind) }
+    }
+}
+
+/// Same as nom's `tag`, remembering the literal that was expected
+pub fn tag<'a>(t: &'static str) -> impl Parser<&'a str, Output = &'a str, Error = Error<'a>> {
+    move |input: &'a str| match input.strip_prefix(t) {
+        Some(rest) => Ok((rest, &input[..t.len()])),
+        None => Err(nom::Err::Error(Error { input, expected: Expected::Tag(t) })),
+    }
+}
+
+/// Converts the outcome of a parser run over a slice of `input` into its value or a located error
+pub fn finish<'a, T>(input: &'a str, result: IResult<'a, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((_rest, value)) => Ok(value),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(input, e.input, e.expected.to_string())),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, &input[input.len()..], "more input")),
+    }
+}
+
+/// Lines of a rectangular grid, rejecting empty input and rows of uneven width in characters
+pub fn grid_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines
+        .first()
+        .map(|line| line.chars().count())
+        .filter(|&width| width > 0)
+        .ok_or_else(|| ParseError::at(input, input, "grid row"))?;
+    match lines.iter().find(|line| line.chars().count() != width) {
+        Some(line) => {
+            let rest = line
+                .char_indices()
+                .nth(width)
+                .map_or(&line[line.len()..], |(i, _)| &line[i..]);
+            Err(ParseError::at(input, rest, format!("row of width {width}")))
+        },
+        None => Ok(lines),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{char, usize},
+        combinator::all_consuming,
+        sequence::separated_pair,
+    };
+
+    use super::*;
+
+    #[test]
+    fn report_expected_char() {
+        let input = "1,2\n3;4\n";
+        let line = input.lines().nth(1).expect("Expecting a second line");
+        let result = all_consuming(separated_pair(usize, char(','), usize)).parse(line);
+        assert_eq!(finish(input, result), Err(ParseError::new(2, 2, ";4", "','")));
+    }
+
+    #[test]
+    fn reject_uneven_grid() {
+        let input = "...\n..\n...\n";
+        assert_eq!(grid_lines(input), Err(ParseError::new(2, 3, "", "row of width 3")));
+    }
+
+    #[test]
+    fn report_expected_tag() {
+        let input = "p=1";
+        let result = (tag("p="), tag("v=")).parse(input);
+        assert_eq!(finish(input, result), Err(ParseError::new(1, 3, "1", "'v='")));
+    }
+}
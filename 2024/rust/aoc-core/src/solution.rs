@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use crate::{Options, ParseError};
+
+/// A day of the puzzle, parsing its input once and solving both parts over it
+///
+/// Both parts are implemented as `partN`, overriding `try_partN` as well when a well-formed
+/// input may have no answer
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer: Display;
+
+    /// Keys of the options understood by `configure`
+    const OPTIONS: &'static [&'static str] = &[];
+
+    /// Names of the extra answers given by `extra`, run like named parts
+    const EXTRAS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Adjusts the parsed input to the options given on the command line
+    fn configure(input: Self::Input, _options: &Options) -> Result<Self::Input, String> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+
+    /// Answer of part 1, or why the input has none
+    fn try_part1(input: &Self::Input) -> Result<Self::Answer, String> {
+        Ok(Self::part1(input))
+    }
+
+    /// Answer of part 2, or why the input has none
+    fn try_part2(input: &Self::Input) -> Result<Self::Answer, String> {
+        Ok(Self::part2(input))
+    }
+
+    /// Extra answer called `name` over the same input, one of `EXTRAS`
+    fn extra(_name: &str, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Diagnostic of the input, one line per record, for the days able to explain their answers
+    fn explain(_input: &Self::Input) -> Option<Vec<String>> {
+        None
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Options, Solution};
+use serde::Serialize;
+
+use crate::registry::{self, Error};
+
+/// Timings of a step over every iteration, in nanoseconds
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub mean_ns: u128,
+    pub max_ns: u128,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Stats {
+    fn new(samples: &[Duration]) -> Self {
+        let nanos = || samples.iter().map(Duration::as_nanos);
+        Self {
+            min_ns: nanos().min().unwrap_or_default(),
+            mean_ns: nanos().sum::<u128>() / samples.len().max(1) as u128,
+            max_ns: nanos().max().unwrap_or_default(),
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns as u64)
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = black_box(f());
+    (value, start.elapsed())
+}
+
+/// Times parse, part 1 and part 2 separately, `iterations` times each, the parts running over
+/// the input adjusted to `options`
+pub fn measure<S: Solution>(input: &str, options: &Options, iterations: usize) -> Result<Report, Error> {
+    registry::check_options::<S>(options)?;
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let (parsed, elapsed) = time(|| S::parse(black_box(input)));
+        parse.push(elapsed);
+        let configured = S::configure(parsed.map_err(Error::Parse)?, options).map_err(Error::Option)?;
+        let (answer, elapsed) = time(|| S::try_part1(&configured));
+        answer.map_err(Error::Answer)?;
+        part1.push(elapsed);
+        let (answer, elapsed) = time(|| S::try_part2(&configured));
+        answer.map_err(Error::Answer)?;
+        part2.push(elapsed);
+    }
+    Ok(Report {
+        day: S::DAY,
+        iterations,
+        parse: Stats::new(&parse),
+        part1: Stats::new(&part1),
+        part2: Stats::new(&part2),
+    })
+}
+
+/// Table of the mean time of every step
+pub fn table(reports: &[Report]) -> String {
+    let mut table = format!("{:>3}  {:>12}  {:>12}  {:>12}  {:>12}\n", "Day", "Parse", "Part 1", "Part 2", "Total");
+    for r in reports {
+        let total = r.parse.mean() + r.part1.mean() + r.part2.mean();
+        table.push_str(&format!(
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+            r.day,
+            format!("{:.2?}", r.parse.mean()),
+            format!("{:.2?}", r.part1.mean()),
+            format!("{:.2?}", r.part2.mean()),
+            format!("{:.2?}", total),
+        ));
+    }
+    table
+}
+
+pub fn json(reports: &[Report]) -> String {
+    serde_json::to_string_pretty(reports).expect("Expected serializable reports")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::ParseError;
+
+    use super::*;
+
+    /// Day whose part 2 never has an answer
+    struct Unsolvable;
+
+    impl Solution for Unsolvable {
+        const DAY: u8 = 99;
+
+        type Input = ();
+        type Answer = usize;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> usize {
+            0
+        }
+
+        fn part2(_input: &Self::Input) -> usize {
+            unreachable!("Expecting try_part2 to be timed instead")
+        }
+
+        fn try_part2(_input: &Self::Input) -> Result<usize, String> {
+            Err("no answer".to_string())
+        }
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [Duration::from_nanos(30), Duration::from_nanos(10), Duration::from_nanos(20)];
+        assert_eq!(Stats::new(&samples), Stats { min_ns: 10, mean_ns: 20, max_ns: 30 });
+    }
+
+    #[test]
+    fn measure_every_step() {
+        let report = measure::<day_01::Day01>("3   4\n4   3\n", &Options::default(), 3).expect("Expecting successful parsing");
+        assert_eq!((report.day, report.iterations), (1, 3));
+        assert!(report.parse.min_ns <= report.parse.max_ns);
+    }
+
+    #[test]
+    fn measure_invalid_input() {
+        let result = measure::<day_01::Day01>("3   x\n", &Options::default(), 3);
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn measure_unsolvable_part() {
+        let result = measure::<Unsolvable>("", &Options::default(), 3);
+        assert_eq!(result.err(), Some(Error::Answer("no answer".to_string())));
+    }
+
+    #[test]
+    fn measure_with_options() {
+        let options: Options = [("arena".to_string(), "11x7".to_string())].into_iter().collect();
+        let robots = "p=0,0 v=1,1\np=5,3 v=0,0\n";
+        let result = measure::<day_14::prelude::Day14>(robots, &options, 1);
+        assert_eq!(result.err(), Some(Error::Answer(day_14::prelude::TreeError::NotFound(77).to_string())));
+        let options: Options = [("size".to_string(), "11x7".to_string())].into_iter().collect();
+        let result = measure::<day_14::prelude::Day14>(robots, &options, 1);
+        assert_eq!(result.err(), Some(Error::Option("Day 14 has no option \"size\"".to_string())));
+    }
+
+    #[test]
+    fn json_report() {
+        let report = Report {
+            day: 7,
+            iterations: 1,
+            parse: Stats { min_ns: 1, mean_ns: 1, max_ns: 1 },
+            part1: Stats { min_ns: 2, mean_ns: 2, max_ns: 2 },
+            part2: Stats { min_ns: 3, mean_ns: 3, max_ns: 3 },
+        };
+        let value: serde_json::Value = serde_json::from_str(&json(&[report])).expect("Expecting valid JSON");
+        assert_eq!(value[0]["day"], 7);
+        assert_eq!(value[0]["part2"]["mean_ns"], 3);
+    }
+}
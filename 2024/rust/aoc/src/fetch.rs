@@ -0,0 +1,108 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::http::Http;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Cache of downloaded inputs, under the user cache directory
+pub fn default_cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc")
+}
+
+/// Downloads puzzle inputs, keeping every input on disk so it is downloaded once
+pub struct Fetcher<H> {
+    http: H,
+    base_url: String,
+    cache: PathBuf,
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, base_url: &str, cache: PathBuf) -> Self {
+        Self { http, base_url: base_url.trim_end_matches('/').to_string(), cache }
+    }
+
+    pub fn cached_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// Input of the puzzle, read from the cache when already downloaded
+    pub fn input(&self, year: u16, day: u8, session: Option<&str>) -> Result<String, String> {
+        let path = self.cached_path(year, day);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {e}", path.display()));
+        }
+
+        let session = session.ok_or("Missing session token to download the input, set AOC_SESSION")?;
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self.http
+            .get(&url, session)
+            .map_err(|e| format!("Cannot download {url}: {e}"))?;
+
+        // Written aside first, so an interrupted write never leaves a partial input in the cache
+        let dir = path.parent().expect("Expecting cached input inside a directory");
+        let partial = path.with_extension("part");
+        fs::create_dir_all(dir)
+            .and_then(|_| fs::write(&partial, &input))
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| format!("Cannot cache {}: {e}", path.display()))?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{stand_in::Server, Client};
+
+    fn cache_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{test}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn download_once() {
+        let server = Server::new(vec![(200, "3   4\n"), (200, "changed\n")]);
+        let cache = cache_dir("once");
+        let fetcher = Fetcher::new(Client::new(), &server.url, cache.clone());
+
+        assert_eq!(fetcher.input(2024, 1, Some("abc")), Ok("3   4\n".to_string()));
+        assert_eq!(fetcher.input(2024, 1, Some("abc")), Ok("3   4\n".to_string()));
+        assert_eq!(server.requests().len(), 1, "The second input comes from the cache");
+        assert!(cache.join("2024").join("day-01.txt").exists());
+        let _ = fs::remove_dir_all(cache);
+    }
+
+    #[test]
+    fn cached_without_session() {
+        let cache = cache_dir("cached");
+        let fetcher = Fetcher::new(Client::new(), "http://127.0.0.1:9", cache.clone());
+        fs::create_dir_all(cache.join("2023")).expect("Expecting a cache directory");
+        fs::write(fetcher.cached_path(2023, 5), "seeds: 1\n").expect("Expecting a cached input");
+
+        assert_eq!(fetcher.input(2023, 5, None), Ok("seeds: 1\n".to_string()));
+        assert!(fetcher.input(2023, 6, None).is_err_and(|e| e.contains("session")));
+        let _ = fs::remove_dir_all(cache);
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let server = Server::new(vec![(400, "Puzzle inputs differ by user.")]);
+        let cache = cache_dir("failed");
+        let fetcher = Fetcher::new(Client::new(), &server.url, cache.clone());
+
+        let result = fetcher.input(2024, 2, Some("expired"));
+        assert!(result.is_err_and(|e| e.contains("400")));
+        assert!(!fetcher.cached_path(2024, 2).exists());
+        let _ = fs::remove_dir_all(cache);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+const USER_AGENT: &str = "github.com/jcdeargaez/advent-of-code runner";
+
+/// Failure talking to the puzzle server
+#[derive(Debug, PartialEq)]
+pub enum HttpError {
+    /// The server answered with an unsuccessful status code and body
+    Status(u16, String),
+    /// The server could not be reached or its answer could not be read
+    Transport(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Status(code, body) => write!(f, "server answered {code}: {}", body.trim()),
+            HttpError::Transport(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for HttpError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(code, response) => HttpError::Status(code, response.into_string().unwrap_or_default()),
+            // The transport error itself repeats the url, which callers already report
+            ureq::Error::Transport(e) => HttpError::Transport(
+                [Some(e.kind().to_string()), e.message().map(str::to_string), e.source().map(|s| s.to_string())]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(": ")
+            ),
+        }
+    }
+}
+
+/// Requests made to the puzzle server, so tests can swap it for a local stand-in
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError>;
+
+    /// Posts `form` url-encoded
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError>;
+}
+
+/// Client of the real puzzle server
+pub struct Client {
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new() -> Self {
+        Self { agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build() }
+    }
+}
+
+impl Http for Client {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call()?
+            .into_string()
+            .map_err(|e| HttpError::Transport(e.to_string()))
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, HttpError> {
+        self.agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form)?
+            .into_string()
+            .map_err(|e| HttpError::Transport(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+pub mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Local server answering each connection with the next canned status and body
+    pub struct Server {
+        pub url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Server {
+        pub fn new(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Expecting a free local port");
+            let url = format!("http://{}", listener.local_addr().expect("Expecting a bound address"));
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let Ok((stream, _)) = listener.accept() else { return };
+                    let mut reader = BufReader::new(&stream);
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                            break;
+                        }
+                        if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                            length = value.trim().parse().unwrap_or(0);
+                        }
+                        request.push_str(&line);
+                    }
+                    let mut content = vec![0; length];
+                    let _ = reader.read_exact(&mut content);
+                    request.push_str("\r\n");
+                    request.push_str(&String::from_utf8_lossy(&content));
+                    recorded.lock().expect("Expecting requests lock").push(request);
+
+                    let response = format!(
+                        "HTTP/1.1 {status} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = (&stream).write_all(response.as_bytes());
+                }
+            });
+            Self { url, requests }
+        }
+
+        /// Raw requests received so far
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().expect("Expecting requests lock").clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stand_in::Server, *};
+
+    #[test]
+    fn get_with_session() {
+        let server = Server::new(vec![(200, "1 2\n")]);
+        let body = Client::new().get(&format!("{}/2024/day/1/input", server.url), "abc");
+        assert_eq!(body, Ok("1 2\n".to_string()));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.to_ascii_lowercase().contains("cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn post_form() {
+        let server = Server::new(vec![(200, "ok")]);
+        let body = Client::new().post(&format!("{}/2024/day/1/answer", server.url), "abc", &[("level", "1"), ("answer", "11")]);
+        assert_eq!(body, Ok("ok".to_string()));
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=11"));
+    }
+
+    #[test]
+    fn unsuccessful_status() {
+        let server = Server::new(vec![(404, "Not unlocked yet")]);
+        let body = Client::new().get(&format!("{}/2024/day/26/input", server.url), "abc");
+        assert_eq!(body, Err(HttpError::Status(404, "Not unlocked yet".to_string())));
+    }
+}
@@ -0,0 +1,45 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{day:02}"))
+}
+
+/// Puzzle input committed next to the crate of `day`
+pub fn default_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+/// Recorded answers of `day`, kept next to its input
+pub fn answers_path(day: u8) -> PathBuf {
+    day_dir(day).join("answers.txt")
+}
+
+fn is_stdin(path: Option<&Path>) -> bool {
+    path.is_none_or(|path| path == Path::new("-"))
+}
+
+/// Name of the input as shown in error messages
+pub fn name(path: Option<&Path>) -> String {
+    match path {
+        Some(path) if !is_stdin(Some(path)) => path.display().to_string(),
+        _ => "<stdin>".to_string(),
+    }
+}
+
+/// Reads the puzzle input from `path`, or from stdin when no path (or `-`) is given
+pub fn read(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) if !is_stdin(Some(path)) => fs::read_to_string(path),
+        _ => {
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content)?;
+            Ok(content)
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::Options;
+use clap::{Args, Parser, Subcommand};
+
+mod bench;
+mod fetch;
+mod http;
+mod input;
+mod registry;
+mod submit;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Access to the puzzle server
+#[derive(Args)]
+struct Remote {
+    /// Session cookie of the logged in user
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Directory caching the downloaded inputs
+    #[arg(long, env = "AOC_CACHE")]
+    cache: Option<PathBuf>,
+
+    /// Puzzle server
+    #[arg(long, env = "AOC_URL", default_value = fetch::BASE_URL, hide = true)]
+    url: String,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a day against an input
+    Run {
+        /// Day of the puzzle
+        #[arg(short, long)]
+        day: u8,
+
+        /// Part of the puzzle, 1, 2 or the name of an extra answer of the day, runs both parts when omitted
+        #[arg(short, long)]
+        part: Option<String>,
+
+        /// Input file, reads stdin when omitted or `-`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Puzzle setting of the day as `key=value`, e.g. `arena=11x7` for day 14
+        #[arg(short, long = "option", value_parser = Options::pair)]
+        options: Vec<(String, String)>,
+
+        /// Prints the diagnostic of each record instead of the answers, for the days able to explain them
+        #[arg(long, conflicts_with = "part")]
+        explain: bool,
+    },
+
+    /// Times parse, part 1 and part 2 of the registered days against their inputs
+    Bench {
+        /// Day to benchmark, benchmarks every registered day when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Runs of every step per day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Puzzle setting of the benchmarked day as `key=value`, e.g. `detector=variance` for day 14
+        #[arg(short, long = "option", value_parser = Options::pair)]
+        options: Vec<(String, String)>,
+
+        /// Also writes the timings as JSON to this file, `-` for stdout
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+
+    /// Prints the input of a puzzle, downloading it unless already cached
+    Fetch {
+        /// Year of the puzzle
+        #[arg(short, long, default_value_t = 2024)]
+        year: u16,
+
+        /// Day of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Writes the input to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        #[command(flatten)]
+        remote: Remote,
+    },
+
+    /// Posts the answer of a puzzle part, unless the same guess was already judged
+    Submit {
+        /// Year of the puzzle
+        #[arg(short, long, default_value_t = 2024)]
+        year: u16,
+
+        /// Day of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit, solved from the committed input of a registered 2024 day when omitted
+        answer: Option<String>,
+
+        #[command(flatten)]
+        remote: Remote,
+    },
+
+    /// Checks the registered days against the answers recorded next to their inputs
+    Verify {
+        /// Day to verify, verifies every registered day when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+}
+
+fn run(day: u8, part: Option<String>, path: Option<PathBuf>, options: Options, explain: bool) -> Result<(), String> {
+    let day = registry::find(day).ok_or(format!("Day {day} is not registered"))?;
+    let content = input::read(path.as_deref()).map_err(|e| format!("Cannot read input: {e}"))?;
+    let source = input::name(path.as_deref());
+    if explain {
+        let lines = (day.explain)(&content, &options)
+            .map_err(|e| e.describe(&source))?
+            .ok_or(format!("Day {} cannot explain its answers", day.day))?;
+        for line in lines {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec!["1".to_string(), "2".to_string()],
+    };
+    for part in parts {
+        let available = ["1", "2"].iter().chain(day.extras).copied().collect::<Vec<_>>().join(", ");
+        let answer = day
+            .solve(&part, &content, &options)
+            .ok_or(format!("Part {part} is not available, expecting one of {available}"))?
+            .map_err(|e| e.describe(&source))?;
+        println!("Part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn selected_days(day: Option<u8>) -> Result<Vec<&'static registry::Day>, String> {
+    match day {
+        Some(day) => registry::find(day)
+            .map(|d| vec![d])
+            .ok_or(format!("Day {day} is not registered")),
+        None => Ok(registry::DAYS.iter().collect()),
+    }
+}
+
+fn bench(day: Option<u8>, iterations: u64, options: Options, json: Option<PathBuf>) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for day in selected_days(day)? {
+        let path = input::default_path(day.day);
+        let content = input::read(Some(&path))
+            .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+        let report = (day.bench)(&content, &options, iterations as usize)
+            .map_err(|e| e.describe(&path.display().to_string()))?;
+        reports.push(report);
+    }
+
+    let table = bench::table(&reports);
+    match json {
+        Some(path) if path == Path::new("-") => println!("{}", bench::json(&reports)),
+        Some(path) => {
+            print!("{table}");
+            fs::write(&path, bench::json(&reports))
+                .map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+        },
+        None => print!("{table}"),
+    }
+    Ok(())
+}
+
+fn verify(day: Option<u8>) -> Result<(), String> {
+    let mut summary = verify::Summary::default();
+    for day in selected_days(day)? {
+        let path = input::default_path(day.day);
+        let content = input::read(Some(&path))
+            .map_err(|e| format!("Cannot read {}: {e}", path.display()));
+        let answers = fs::read_to_string(input::answers_path(day.day)).unwrap_or_default();
+        for part in 1..=2 {
+            let actual = content
+                .as_deref()
+                .map_err(String::clone)
+                .and_then(|content| {
+                    let solve = day.part(part).ok_or(format!("Part {part} is not available"))?;
+                    solve(content, &Options::default()).map_err(|e| e.describe(&path.display().to_string()))
+                });
+            let outcome = match actual {
+                Ok(actual) => verify::Outcome::new(verify::expected(&answers, part), &actual),
+                Err(e) => verify::Outcome::Error(e),
+            };
+            println!("Day {:>2} part {part}: {outcome}", day.day);
+            summary.add(&outcome);
+        }
+    }
+
+    println!("{summary}");
+    if summary.failed > 0 {
+        Err(format!("{} answers do not match", summary.failed))
+    } else {
+        Ok(())
+    }
+}
+
+fn fetch(year: u16, day: u8, output: Option<PathBuf>, remote: Remote) -> Result<(), String> {
+    let cache = remote.cache.unwrap_or_else(fetch::default_cache_dir);
+    let fetcher = fetch::Fetcher::new(http::Client::new(), &remote.url, cache);
+    let input = fetcher.input(year, day, remote.session.as_deref())?;
+    match output {
+        Some(path) => fs::write(&path, input).map_err(|e| format!("Cannot write {}: {e}", path.display())),
+        None => {
+            print!("{input}");
+            Ok(())
+        },
+    }
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>, remote: Remote) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None if year == 2024 => {
+            let registered = registry::find(day).ok_or(format!("Day {day} is not registered"))?;
+            let solve = registered.part(part).ok_or(format!("Part {part} is not available"))?;
+            let path = input::default_path(day);
+            let content = input::read(Some(&path))
+                .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+            solve(&content, &Options::default()).map_err(|e| e.describe(&path.display().to_string()))?
+        },
+        None => return Err(format!("Missing answer to submit for {year}")),
+    };
+
+    let cache = remote.cache.unwrap_or_else(fetch::default_cache_dir);
+    let submitter = submit::Submitter::new(http::Client::new(), &remote.url, cache);
+    let submission = submitter.submit(year, day, part, &answer, remote.session.as_deref())?;
+    let source = if submission.remembered { " (remembered)" } else { "" };
+    println!("Part {part}: {answer} is {}{source}", submission.verdict);
+    match submission.verdict {
+        submit::Verdict::Correct => Ok(()),
+        verdict => Err(format!("Answer not accepted: {verdict}")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run { day, part, input, options, explain } => {
+            run(day, part, input, options.into_iter().collect(), explain)
+        }
+        Command::Bench { day, iterations, options, json } => bench(day, iterations, options.into_iter().collect(), json),
+        Command::Fetch { year, day, output, remote } => fetch(year, day, output, remote),
+        Command::Submit { year, day, part, answer, remote } => submit(year, day, part, answer, remote),
+        Command::Verify { day } => verify(day),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
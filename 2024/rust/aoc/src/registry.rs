@@ -0,0 +1,195 @@
+use aoc_core::{Options, ParseError, Solution};
+
+use crate::bench::{self, Report};
+
+/// Reason a part could not be solved
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Parse(ParseError),
+    Option(String),
+    /// The input is well formed but the part has no answer for it
+    Answer(String),
+}
+
+impl Error {
+    /// Message of the error, parse errors being located in `source`
+    pub fn describe(&self, source: &str) -> String {
+        match self {
+            Error::Parse(e) => format!("{source}:{e}"),
+            Error::Option(e) => e.clone(),
+            Error::Answer(e) => format!("{source}: {e}"),
+        }
+    }
+}
+
+pub type Part = fn(&str, &Options) -> Result<String, Error>;
+
+pub type Extra = fn(&str, &str, &Options) -> Result<String, Error>;
+
+pub type Explain = fn(&str, &Options) -> Result<Option<Vec<String>>, Error>;
+
+pub type Bench = fn(&str, &Options, usize) -> Result<Report, Error>;
+
+pub struct Day {
+    pub day: u8,
+    pub part1: Part,
+    pub part2: Part,
+    pub extras: &'static [&'static str],
+    pub extra: Extra,
+    pub explain: Explain,
+    pub bench: Bench,
+}
+
+/// Rejects the options the day does not understand
+pub fn check_options<S: Solution>(options: &Options) -> Result<(), Error> {
+    match options.keys().find(|key| !S::OPTIONS.contains(key)) {
+        Some(key) => Err(Error::Option(format!("Day {} has no option \"{key}\"", S::DAY))),
+        None => Ok(()),
+    }
+}
+
+fn configured<S: Solution>(input: &str, options: &Options) -> Result<S::Input, Error> {
+    check_options::<S>(options)?;
+    let input = S::parse(input).map_err(Error::Parse)?;
+    S::configure(input, options).map_err(Error::Option)
+}
+
+fn part1<S: Solution>(input: &str, options: &Options) -> Result<String, Error> {
+    let answer = S::try_part1(&configured::<S>(input, options)?).map_err(Error::Answer)?;
+    Ok(answer.to_string())
+}
+
+fn part2<S: Solution>(input: &str, options: &Options) -> Result<String, Error> {
+    let answer = S::try_part2(&configured::<S>(input, options)?).map_err(Error::Answer)?;
+    Ok(answer.to_string())
+}
+
+fn extra<S: Solution>(name: &str, input: &str, options: &Options) -> Result<String, Error> {
+    let input = configured::<S>(input, options)?;
+    S::extra(name, &input).ok_or(Error::Option(format!("Day {} has no extra \"{name}\"", S::DAY)))
+}
+
+fn explain<S: Solution>(input: &str, options: &Options) -> Result<Option<Vec<String>>, Error> {
+    Ok(S::explain(&configured::<S>(input, options)?))
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            extras: S::EXTRAS,
+            extra: extra::<S>,
+            explain: explain::<S>,
+            bench: bench::measure::<S>,
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day_01::Day01>(),
+    Day::of::<day_02::Day02>(),
+    Day::of::<day_03::Day03>(),
+    Day::of::<day_04::Day04>(),
+    Day::of::<day_05::Day05>(),
+    Day::of::<day_06::Day06>(),
+    Day::of::<day_07::prelude::Day07>(),
+    Day::of::<day_08::Day08>(),
+    Day::of::<day_09::Day09>(),
+    Day::of::<day_10::Day10>(),
+    Day::of::<day_11::Day11>(),
+    Day::of::<day_12::Day12>(),
+    Day::of::<day_13::Day13>(),
+    Day::of::<day_14::prelude::Day14>(),
+    Day::of::<day_15::prelude::Day15>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS
+        .iter()
+        .find(|d| d.day == day)
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    /// Solver of a part given by number or by the name of one of the extras
+    pub fn solve(&self, part: &str, input: &str, options: &Options) -> Option<Result<String, Error>> {
+        match part.parse::<u8>() {
+            Ok(part) => self.part(part).map(|solve| solve(input, options)),
+            Err(_) if self.extras.contains(&part) => Some((self.extra)(part, input, options)),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days: Vec<u8> = DAYS
+            .iter()
+            .map(|d| d.day)
+            .collect();
+        assert_eq!(days, (1..=15).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn find_day() {
+        assert_eq!(find(7).map(|d| d.day), Some(7));
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn run_registered_part() {
+        let day = find(1).expect("Expecting day 1 to be registered");
+        let part = day.part(1).expect("Expecting part 1");
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(part(input, &Options::default()), Ok("11".to_string()));
+    }
+
+    #[test]
+    fn run_named_extra() {
+        let day = find(1).expect("Expecting day 1 to be registered");
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(day.solve("2", input, &Options::default()), Some(Ok("31".to_string())));
+        assert_eq!(day.solve("intersection", input, &Options::default()), Some(Ok("4".to_string())));
+        assert_eq!(day.solve("cosine", input, &Options::default()), None);
+        assert_eq!(find(2).and_then(|d| d.solve("jaccard", input, &Options::default())), None);
+    }
+
+    #[test]
+    fn explain_registered_day() {
+        let input = "1 3 2 4 5\n";
+        let explain = |day| find(day).map(|d| (d.explain)(input, &Options::default()));
+        let expected = "Report 1: unsafe at indices 1 and 2 (direction change), safe without index 1";
+        assert_eq!(explain(2), Some(Ok(Some(vec![expected.to_string()]))));
+        assert_eq!(explain(3), Some(Ok(None)));
+    }
+
+    #[test]
+    fn report_missing_answer() {
+        let day = find(14).expect("Expecting day 14 to be registered");
+        let options = [("detector".to_string(), "variance".to_string())].into_iter().collect();
+        let result = (day.part2)("", &options);
+        assert_eq!(result, Err(Error::Answer("no robots to draw a Christmas tree".to_string())));
+        assert_eq!(result.unwrap_err().describe("<stdin>"), "<stdin>: no robots to draw a Christmas tree");
+    }
+
+    #[test]
+    fn reject_unknown_option() {
+        let day = find(1).expect("Expecting day 1 to be registered");
+        let options = [("arena".to_string(), "small".to_string())].into_iter().collect();
+        let result = (day.part1)("3   4\n", &options);
+        assert_eq!(result, Err(Error::Option("Day 1 has no option \"arena\"".to_string())));
+    }
+}
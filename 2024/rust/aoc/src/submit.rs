@@ -0,0 +1,352 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::http::Http;
+
+/// Answer of the puzzle server to a submission
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the previous answer, seconds left to wait
+    Wait(u64),
+    /// The part was already solved, or its first part is not solved yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Key of the verdict in the file of remembered guesses
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too-high"),
+            Verdict::TooLow => Some("too-low"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::Wait(_) | Verdict::WrongLevel | Verdict::Unknown => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(seconds) => write!(f, "too soon, wait {seconds}s"),
+            Verdict::WrongLevel => write!(f, "not the level being solved"),
+            Verdict::Unknown => write!(f, "unrecognized answer from the server"),
+        }
+    }
+}
+
+/// Seconds of a wait such as `1m 23s`
+fn parse_wait(text: &str) -> Option<u64> {
+    text.split_whitespace()
+        .map(|part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// Seconds of a wait spelled out such as `one minute` or `5 minutes`
+fn parse_spelled_wait(text: &str) -> Option<u64> {
+    const NUMBERS: [&str; 10] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten"];
+    let (n, unit) = text.split_once(' ')?;
+    let n = match NUMBERS.iter().position(|&word| word == n) {
+        Some(i) => i as u64 + 1,
+        None => n.parse().ok()?,
+    };
+    match unit.trim_end_matches('s') {
+        "hour" => Some(n * 3600),
+        "minute" => Some(n * 60),
+        "second" => Some(n),
+        _ => None,
+    }
+}
+
+/// Seconds to wait before answering again after a wrong answer, as in `Please wait one
+/// minute before trying again`
+pub fn parse_cooldown(page: &str) -> Option<u64> {
+    page
+        .split_once("lease wait ")
+        .and_then(|(_, rest)| rest.split_once(" before trying again"))
+        .and_then(|(wait, _)| parse_spelled_wait(wait))
+}
+
+/// Verdict found in the page answering a submission
+pub fn parse_verdict(page: &str) -> Verdict {
+    if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("That's not the right answer") {
+        if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if page.contains("You gave an answer too recently") {
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        Verdict::Wait(wait.unwrap_or(60))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Verdict of a submission, possibly known without asking the server again
+#[derive(Debug, PartialEq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    pub remembered: bool,
+}
+
+fn write(path: &Path, content: String) -> Result<(), String> {
+    fs::create_dir_all(path.parent().expect("Expecting a cache directory"))
+        .and_then(|_| fs::write(path, content))
+        .map_err(|e| format!("Cannot remember the verdict in {}: {e}", path.display()))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Posts answers, remembering the verdicts so the same guess is never submitted twice
+pub struct Submitter<H> {
+    http: H,
+    base_url: String,
+    cache: PathBuf,
+    clock: fn() -> u64,
+}
+
+impl<H: Http> Submitter<H> {
+    pub fn new(http: H, base_url: &str, cache: PathBuf) -> Self {
+        Self { http, base_url: base_url.trim_end_matches('/').to_string(), cache, clock: now }
+    }
+
+    fn guesses_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache
+            .join(year.to_string())
+            .join(format!("day-{day:02}.guesses"))
+    }
+
+    fn wait_path(&self, year: u16) -> PathBuf {
+        self.cache
+            .join(year.to_string())
+            .join("wait-until")
+    }
+
+    /// Verdicts of the answers already submitted for `part`
+    fn guesses(&self, year: u16, day: u8, part: u8) -> Vec<(String, Verdict)> {
+        fs::read_to_string(self.guesses_path(year, day))
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split(' ');
+                let guess_part = fields.next()?.parse::<u8>().ok()?;
+                let answer = fields.next()?;
+                let verdict = Verdict::from_key(fields.next()?)?;
+                (guess_part == part).then(|| (answer.to_string(), verdict))
+            })
+            .collect()
+    }
+
+    /// Verdict of `answer` deduced from previous guesses, bounding numbers by the guesses
+    /// found too high or too low
+    fn remembered(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Verdict> {
+        let guesses = self.guesses(year, day, part);
+        if let Some((_, verdict)) = guesses.iter().find(|(guess, _)| guess == answer) {
+            return Some(verdict.clone());
+        }
+        let answer: i128 = answer.parse().ok()?;
+        guesses
+            .iter()
+            .find_map(|(guess, verdict)| {
+                let guess: i128 = guess.parse().ok()?;
+                match verdict {
+                    Verdict::TooHigh if answer >= guess => Some(Verdict::TooHigh),
+                    Verdict::TooLow if answer <= guess => Some(Verdict::TooLow),
+                    _ => None,
+                }
+            })
+    }
+
+    fn remember(&self, year: u16, day: u8, part: u8, answer: &str, verdict: &Verdict) -> Result<(), String> {
+        let (path, content) = match verdict {
+            Verdict::Wait(seconds) => return self.remember_wait(year, *seconds),
+            verdict => {
+                let Some(key) = verdict.key() else { return Ok(()) };
+                let path = self.guesses_path(year, day);
+                let mut content = fs::read_to_string(&path).unwrap_or_default();
+                content.push_str(&format!("{part} {answer} {key}\n"));
+                (path, content)
+            },
+        };
+        write(&path, content)
+    }
+
+    /// Keeps the server from being asked again for `seconds`
+    fn remember_wait(&self, year: u16, seconds: u64) -> Result<(), String> {
+        write(&self.wait_path(year), format!("{}\n", (self.clock)() + seconds))
+    }
+
+    /// Submits `answer` unless its verdict is already known, or the server asked to wait
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str, session: Option<&str>) -> Result<Submission, String> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(format!("Invalid answer \"{answer}\""));
+        }
+        if let Some(verdict) = self.remembered(year, day, part, answer) {
+            return Ok(Submission { verdict, remembered: true });
+        }
+        let wait_until = fs::read_to_string(self.wait_path(year))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let now = (self.clock)();
+        if wait_until > now {
+            return Ok(Submission { verdict: Verdict::Wait(wait_until - now), remembered: true });
+        }
+
+        let session = session.ok_or("Missing session token to submit the answer, set AOC_SESSION")?;
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let page = self.http
+            .post(&url, session, &[("level", &level), ("answer", answer)])
+            .map_err(|e| format!("Cannot submit to {url}: {e}"))?;
+        let verdict = parse_verdict(&page);
+        self.remember(year, day, part, answer, &verdict)?;
+        if let Some(seconds) = parse_cooldown(&page) {
+            self.remember_wait(year, seconds)?;
+        }
+        Ok(Submission { verdict, remembered: false })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::http::{stand_in::Server, Client};
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const WRONG_WAIT: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+
+    fn cache_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{test}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(parse_verdict(RIGHT), Verdict::Correct);
+        assert_eq!(parse_verdict(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(parse_verdict(TOO_LOW), Verdict::TooLow);
+        assert_eq!(parse_verdict("That's not the right answer."), Verdict::Wrong);
+        assert_eq!(parse_verdict(TOO_SOON), Verdict::Wait(83));
+        assert_eq!(parse_verdict("You don't seem to be solving the right level."), Verdict::WrongLevel);
+        assert_eq!(parse_verdict("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn cooldowns() {
+        assert_eq!(parse_cooldown(WRONG_WAIT), Some(60));
+        assert_eq!(parse_cooldown("Please wait 5 minutes before trying again."), Some(300));
+        assert_eq!(parse_cooldown("please wait 30 seconds before trying again."), Some(30));
+        assert_eq!(parse_cooldown(TOO_HIGH), None);
+        assert_eq!(parse_cooldown("Please wait a while before trying again."), None);
+    }
+
+    #[test]
+    fn remember_wrong_guesses() {
+        let server = Server::new(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, RIGHT)]);
+        let cache = cache_dir("guesses");
+        let submitter = Submitter::new(Client::new(), &server.url, cache.clone());
+        let submit = |answer| submitter.submit(2024, 1, 1, answer, Some("abc")).expect("Expecting a verdict");
+
+        assert_eq!(submit("500"), Submission { verdict: Verdict::TooHigh, remembered: false });
+        assert_eq!(submit("500"), Submission { verdict: Verdict::TooHigh, remembered: true });
+        assert_eq!(submit("700"), Submission { verdict: Verdict::TooHigh, remembered: true });
+        assert_eq!(submit("100"), Submission { verdict: Verdict::TooLow, remembered: false });
+        assert_eq!(submit("50"), Submission { verdict: Verdict::TooLow, remembered: true });
+        assert_eq!(submit("300"), Submission { verdict: Verdict::Correct, remembered: false });
+        assert_eq!(server.requests().len(), 3);
+        assert!(server.requests()[0].ends_with("level=1&answer=500"));
+        let _ = fs::remove_dir_all(cache);
+    }
+
+    #[test]
+    fn wait_before_submitting_again() {
+        let server = Server::new(vec![(200, TOO_SOON), (200, RIGHT)]);
+        let cache = cache_dir("wait");
+        let mut submitter = Submitter::new(Client::new(), &server.url, cache.clone());
+        submitter.clock = || 1_000;
+
+        let result = submitter.submit(2024, 2, 2, "7", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Wait(83), remembered: false }));
+        submitter.clock = || 1_050;
+        let result = submitter.submit(2024, 2, 2, "7", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Wait(33), remembered: true }));
+        submitter.clock = || 1_100;
+        let result = submitter.submit(2024, 2, 2, "7", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Correct, remembered: false }));
+        assert_eq!(server.requests().len(), 2);
+        let _ = fs::remove_dir_all(cache);
+    }
+
+    #[test]
+    fn wait_after_wrong_answer() {
+        let server = Server::new(vec![(200, WRONG_WAIT), (200, RIGHT)]);
+        let cache = cache_dir("cooldown");
+        let mut submitter = Submitter::new(Client::new(), &server.url, cache.clone());
+        submitter.clock = || 1_000;
+
+        let result = submitter.submit(2024, 3, 1, "7", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Wrong, remembered: false }));
+        submitter.clock = || 1_030;
+        let result = submitter.submit(2024, 3, 1, "8", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Wait(30), remembered: true }));
+        assert_eq!(server.requests().len(), 1);
+        submitter.clock = || 1_060;
+        let result = submitter.submit(2024, 3, 1, "8", Some("abc"));
+        assert_eq!(result, Ok(Submission { verdict: Verdict::Correct, remembered: false }));
+        assert_eq!(server.requests().len(), 2);
+        let _ = fs::remove_dir_all(cache);
+    }
+}
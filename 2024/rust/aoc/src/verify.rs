@@ -0,0 +1,103 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Result of checking a part against its recorded answer
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    /// The part could not be solved, counted as a failure
+    Error(String),
+}
+
+impl Outcome {
+    pub fn new(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            None => Outcome::Missing,
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, actual } => write!(f, "fail (expected {expected}, got {actual})"),
+            Outcome::Missing => write!(f, "missing"),
+            Outcome::Error(e) => write!(f, "fail ({e})"),
+        }
+    }
+}
+
+/// Recorded answer of `part` in an answers file, written the way `aoc run` prints it
+pub fn expected(answers: &str, part: u8) -> Option<&str> {
+    let prefix = format!("Part {part}:");
+    answers
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .map(str::trim)
+        .filter(|answer| !answer.is_empty())
+}
+
+/// Totals of every outcome
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail { .. } | Outcome::Error(_) => self.failed += 1,
+            Outcome::Missing => self.missing += 1,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} passed, {} failed, {} missing", self.passed, self.failed, self.missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "Part 1: 11\nPart 2:\n";
+
+    #[test]
+    fn expected_answer() {
+        assert_eq!(expected(ANSWERS, 1), Some("11"));
+        assert_eq!(expected(ANSWERS, 2), None);
+        assert_eq!(expected("", 1), None);
+    }
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(Outcome::new(Some("11"), "11"), Outcome::Pass);
+        assert_eq!(Outcome::new(None, "11"), Outcome::Missing);
+        assert_eq!(
+            Outcome::new(Some("11"), "31").to_string(),
+            "fail (expected 11, got 31)"
+        );
+        assert_eq!(Outcome::Error("no answer".to_string()).to_string(), "fail (no answer)");
+    }
+
+    #[test]
+    fn summarize_outcomes() {
+        let mut summary = Summary::default();
+        [Outcome::Pass, Outcome::Missing, Outcome::Pass].iter().for_each(|o| summary.add(o));
+        assert_eq!(summary.to_string(), "2 passed, 0 failed, 1 missing");
+        summary.add(&Outcome::Error("input.txt:1:1: expected a digit".to_string()));
+        assert_eq!(summary.to_string(), "2 passed, 1 failed, 1 missing");
+    }
+}
@@ -1,6 +1,8 @@
-use day_01::part1;
+use aoc_core::Solution;
+use day_01::Day01;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
+    let input = Day01::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day01::part1(&input));
 }
@@ -1,6 +1,8 @@
-use day_01::part2;
+use aoc_core::Solution;
+use day_01::Day01;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
-}
\ No newline at end of file
+    let input = Day01::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day01::part2(&input));
+}
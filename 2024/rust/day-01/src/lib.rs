@@ -1,39 +1,56 @@
-use itertools::{Either, Itertools};
-
-fn parse_input(input: &str) -> (Vec<usize>, Vec<usize>) {
-    input
-        .lines()
-        .flat_map(|line|
-            line
-                .split_whitespace()
-                .map(|p| p.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>())
-        .enumerate()
-        .partition_map(|(i, v)| if i % 2 == 0 { Either::Left(v) } else { Either::Right(v) })
-}
+use aoc_core::{ParseError, Solution};
+use itertools::Itertools;
+
+pub mod metrics;
+mod parser;
+
+pub use parser::{read_lists, ReadError};
+
+pub struct Day01;
 
-pub fn part1(input: &str) -> usize {
-    let (mut lhs, mut rhs) = parse_input(input);
+fn distance(lhs: &[usize], rhs: &[usize]) -> usize {
+    let mut lhs = lhs.to_vec();
+    let mut rhs = rhs.to_vec();
     lhs.sort();
     rhs.sort();
-    let distance =
-        lhs
-            .iter()
-            .zip(rhs.iter())
-            .map(|(a, b)| a.abs_diff(*b))
-            .sum();
-    distance
+    lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
 }
 
-pub fn part2(input: &str) -> usize {
-    let (lhs, rhs) = parse_input(input);
+fn similarity(lhs: &[usize], rhs: &[usize]) -> usize {
     let f = rhs.iter().counts();
-    let score  =
-        lhs
-            .iter()
-            .map(|a| a * f.get(a).unwrap_or(&0))
-            .sum();
-    score
+    lhs
+        .iter()
+        .map(|a| a * f.get(a).unwrap_or(&0))
+        .sum()
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer = usize;
+
+    const EXTRAS: &'static [&'static str] = &["intersection", "jaccard", "spearman", "median-distance"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1((lhs, rhs): &Self::Input) -> usize {
+        distance(lhs, rhs)
+    }
+
+    fn part2((lhs, rhs): &Self::Input) -> usize {
+        similarity(lhs, rhs)
+    }
+
+    fn extra(name: &str, (lhs, rhs): &Self::Input) -> Option<String> {
+        metrics::named(name, lhs, rhs)
+    }
 }
 
 #[cfg(test)]
@@ -50,13 +67,29 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day01::part1(&Day01::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 11);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day01::part2(&Day01::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 31);
     }
+
+    #[test]
+    fn test_extras() {
+        let input = Day01::parse(INPUT).expect("Expecting successful parsing");
+        let answers: Vec<Option<String>> = Day01::EXTRAS
+            .iter()
+            .map(|name| Day01::extra(name, &input))
+            .collect();
+        assert_eq!(answers, ["4", "0.500000", "-0.096774", "1.5"].map(|a| Some(a.to_string())));
+    }
+
+    #[test]
+    fn test_invalid_location_id() {
+        let result = Day01::parse("3   4\n4   x3\n");
+        assert_eq!(result.err(), Some(ParseError::new(2, 5, "x3", "location id")));
+    }
 }
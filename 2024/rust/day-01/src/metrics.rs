@@ -0,0 +1,123 @@
+use itertools::Itertools;
+
+/// Number of location ids shared by both lists, counting repeated ids as many times as both lists have them
+pub fn intersection(lhs: &[usize], rhs: &[usize]) -> usize {
+    let f = rhs.iter().counts();
+    lhs
+        .iter()
+        .counts()
+        .iter()
+        .map(|(a, n)| *n.min(f.get(a).unwrap_or(&0)))
+        .sum()
+}
+
+/// Size of the intersection over the size of the union of both lists taken as multisets
+pub fn jaccard(lhs: &[usize], rhs: &[usize]) -> Option<f64> {
+    let shared = intersection(lhs, rhs);
+    let union = lhs.len() + rhs.len() - shared;
+    (union > 0).then(|| shared as f64 / union as f64)
+}
+
+/// Ranks of the values, tied values sharing the mean of their ranks
+fn ranks(values: &[usize]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&i| values[i]);
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    for (_, group) in &order.iter().chunk_by(|&&i| values[i]) {
+        let group: Vec<usize> = group.copied().collect();
+        let rank = start as f64 + (group.len() as f64 + 1.0) / 2.0;
+        for i in &group {
+            ranks[*i] = rank;
+        }
+        start += group.len();
+    }
+    ranks
+}
+
+/// Spearman rank correlation between the ids of each line, none when either list is constant
+pub fn spearman(lhs: &[usize], rhs: &[usize]) -> Option<f64> {
+    let (x, y) = (ranks(lhs), ranks(rhs));
+    let n = x.len().min(y.len()) as f64;
+    let mean = (n + 1.0) / 2.0;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y.iter()) {
+        cov += (a - mean) * (b - mean);
+        var_x += (a - mean).powi(2);
+        var_y += (b - mean).powi(2);
+    }
+    (var_x > 0.0 && var_y > 0.0).then(|| cov / (var_x * var_y).sqrt())
+}
+
+/// Median of the distances between the ids paired by rank, as summed by part 1
+pub fn median_distance(lhs: &[usize], rhs: &[usize]) -> Option<f64> {
+    let distances: Vec<usize> = lhs
+        .iter()
+        .sorted()
+        .zip(rhs.iter().sorted())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sorted()
+        .collect();
+    let mid = distances.len() / 2;
+    match distances.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(distances[mid] as f64),
+        _ => Some((distances[mid - 1] + distances[mid]) as f64 / 2.0),
+    }
+}
+
+/// Metric of the lists called `name`, formatted as an answer
+pub fn named(name: &str, lhs: &[usize], rhs: &[usize]) -> Option<String> {
+    let undefined = || "undefined".to_string();
+    let answer = match name {
+        "intersection" => intersection(lhs, rhs).to_string(),
+        "jaccard" => jaccard(lhs, rhs).map_or_else(undefined, |v| format!("{v:.6}")),
+        "spearman" => spearman(lhs, rhs).map_or_else(undefined, |v| format!("{v:.6}")),
+        "median-distance" => median_distance(lhs, rhs).map_or_else(undefined, |v| v.to_string()),
+        _ => return None,
+    };
+    Some(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LHS: [usize; 6] = [3, 4, 2, 1, 3, 3];
+    const RHS: [usize; 6] = [4, 3, 5, 3, 9, 3];
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(intersection(&LHS, &RHS), 4);
+    }
+
+    #[test]
+    fn test_jaccard() {
+        assert_eq!(jaccard(&LHS, &RHS), Some(0.5));
+        assert_eq!(jaccard(&[], &[]), None);
+    }
+
+    #[test]
+    fn test_ranks_share_ties() {
+        assert_eq!(ranks(&LHS), vec![4.0, 6.0, 2.0, 1.0, 4.0, 4.0]);
+    }
+
+    #[test]
+    fn test_spearman() {
+        assert_eq!(spearman(&[1, 2, 3], &[10, 20, 30]), Some(1.0));
+        assert_eq!(spearman(&[1, 2, 3], &[30, 20, 10]), Some(-1.0));
+        assert_eq!(spearman(&[1, 1, 1], &[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_median_distance() {
+        assert_eq!(median_distance(&LHS, &RHS), Some(1.5));
+        assert_eq!(median_distance(&[1, 2, 3], &[1, 5, 3]), Some(1.0));
+        assert_eq!(median_distance(&[], &[]), None);
+    }
+
+    #[test]
+    fn test_unknown_metric() {
+        assert_eq!(named("cosine", &LHS, &RHS), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, BufRead},
+};
+
+use aoc_core::ParseError;
+
+/// Failure reading the location lists
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Left and right location ids of a line, `number` being its 1-based position
+fn parse_line(number: usize, line: &str) -> Result<(usize, usize), ParseError> {
+    let error = |rest: &str, expected: &str| ParseError {
+        line: number,
+        ..ParseError::at(line, rest, expected)
+    };
+    let id = |token: &str| token
+        .parse::<usize>()
+        .map_err(|_| error(token, "location id"));
+
+    let mut tokens = line.split_whitespace();
+    let lhs = id(tokens.next().unwrap_or(&line[line.len()..]))?;
+    let rhs = id(tokens.next().unwrap_or(&line[line.len()..]))?;
+    match tokens.next() {
+        Some(extra) => Err(error(extra, "end of line")),
+        None => Ok((lhs, rhs)),
+    }
+}
+
+/// Both location lists, read a line at a time from `reader` into a single reused buffer
+pub fn read_lists(mut reader: impl BufRead) -> Result<(Vec<usize>, Vec<usize>), ReadError> {
+    let (mut lhs, mut rhs) = (Vec::new(), Vec::new());
+    let mut line = String::new();
+    for number in 1.. {
+        line.clear();
+        if reader.read_line(&mut line).map_err(ReadError::Io)? == 0 {
+            break;
+        }
+        let (l, r) = parse_line(number, line.trim_end_matches(['\n', '\r'])).map_err(ReadError::Parse)?;
+        lhs.push(l);
+        rhs.push(r);
+    }
+    Ok((lhs, rhs))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    read_lists(input.as_bytes()).map_err(|e| match e {
+        ReadError::Parse(e) => e,
+        ReadError::Io(e) => panic!("Reading from memory never fails: {e}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+
+    #[test]
+    fn read_from_buffered_reader() {
+        let reader = BufReader::with_capacity(4, "3   4\r\n4   3\n2   5".as_bytes());
+        let lists = read_lists(reader).expect("Expecting successful reading");
+        assert_eq!(lists, (vec![3, 4, 2], vec![4, 3, 5]));
+    }
+
+    #[test]
+    fn missing_column() {
+        let result = parse_input("3   4\n4\n2   5\n");
+        assert_eq!(result.err(), Some(ParseError::new(2, 2, "", "location id")));
+    }
+
+    #[test]
+    fn extra_column() {
+        let result = parse_input("3   4\n4   3   7\n2   5\n");
+        assert_eq!(result.err(), Some(ParseError::new(2, 9, "7", "end of line")));
+    }
+
+    #[test]
+    fn blank_line() {
+        let result = parse_input("3   4\n\n2   5\n");
+        assert_eq!(result.err(), Some(ParseError::new(2, 1, "", "location id")));
+    }
+}
@@ -1,6 +1,8 @@
-use day_02::part1;
+use aoc_core::Solution;
+use day_02::Day02;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
+    let input = Day02::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day02::part1(&input));
 }
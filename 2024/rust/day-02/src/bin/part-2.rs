@@ -1,6 +1,8 @@
-use day_02::part2;
+use aoc_core::Solution;
+use day_02::Day02;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
+    let input = Day02::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day02::part2(&input));
 }
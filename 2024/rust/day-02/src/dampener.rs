@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+/// Bounds of the difference between adjacent levels of a safe report
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Steps {
+    pub min: isize,
+    pub max: isize,
+}
+
+impl Steps {
+    pub const PUZZLE: Steps = Steps { min: 1, max: 3 };
+
+    pub fn allow(self, step: isize) -> bool {
+        (self.min..=self.max).contains(&step)
+    }
+}
+
+impl Default for Steps {
+    fn default() -> Self {
+        Steps::PUZZLE
+    }
+}
+
+impl FromStr for Steps {
+    type Err = String;
+
+    /// Parses `MIN-MAX`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s
+            .split_once('-')
+            .and_then(|(min, max)| Some((min.parse().ok()?, max.parse().ok()?)))
+            .filter(|&(min, max)| 0 < min && min <= max)
+            .map(|(min, max)| Steps { min, max })
+            .ok_or("expected MIN-MAX with 0 < MIN <= MAX".to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+impl Trend {
+    pub const ALL: [Trend; 2] = [Trend::Increasing, Trend::Decreasing];
+
+    /// Difference from `a` to `b` along the trend
+    pub fn step(self, a: isize, b: isize) -> isize {
+        match self {
+            Trend::Increasing => b - a,
+            Trend::Decreasing => a - b,
+        }
+    }
+}
+
+/// Indices of the first adjacent kept levels from `from` on whose step is out of bounds
+fn first_violation(levels: &[isize], removed: &[usize], from: usize, trend: Trend, steps: Steps) -> Option<(usize, usize)> {
+    let mut kept = (from..levels.len()).filter(|i| !removed.contains(i));
+    let mut prev = kept.next()?;
+    for i in kept {
+        if !steps.allow(trend.step(levels[prev], levels[i])) {
+            return Some((prev, i));
+        }
+        prev = i;
+    }
+    None
+}
+
+/// Index of the kept level before `i`, where scanning resumes once `i` is removed
+fn kept_before(removed: &[usize], i: usize) -> usize {
+    (0..i)
+        .rev()
+        .find(|j| !removed.contains(j))
+        .unwrap_or(0)
+}
+
+/// Levels to remove, at most `removals` more, to make the levels follow the trend,
+/// only the two levels of the first violation being candidates for removal
+fn tolerate(levels: &[isize], removed: &mut Vec<usize>, from: usize, removals: usize, trend: Trend, steps: Steps) -> Option<Vec<usize>> {
+    let Some((a, b)) = first_violation(levels, removed, from, trend, steps) else {
+        return Some(removed.clone());
+    };
+    if removals == 0 {
+        return None;
+    }
+    let candidates = [(a, kept_before(removed, a)), (b, a)];
+    candidates
+        .into_iter()
+        .find_map(|(i, from)| {
+            removed.push(i);
+            let tolerated = tolerate(levels, removed, from, removals - 1, trend, steps);
+            removed.pop();
+            tolerated
+        })
+}
+
+/// Levels to remove, at most `removals`, to make the report safe, in the order they were found
+pub fn dampening(levels: &[isize], steps: Steps, removals: usize) -> Option<Vec<usize>> {
+    Trend::ALL
+        .iter()
+        .find_map(|&trend| tolerate(levels, &mut Vec::with_capacity(removals), 0, removals, trend, steps))
+}
+
+/// Whether the report is safe once at most `removals` of its levels are removed
+pub fn is_dampened(levels: &[isize], steps: Steps, removals: usize) -> bool {
+    dampening(levels, steps, removals).is_some()
+}
+
+/// Whether the report is safe as is
+pub fn is_safe(levels: &[isize], steps: Steps) -> bool {
+    is_dampened(levels, steps, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn brute_force(levels: &[isize], steps: Steps, removals: usize) -> bool {
+        (0..=removals.min(levels.len()))
+            .flat_map(|k| (0..levels.len()).combinations(k))
+            .any(|removed| {
+                let kept: Vec<isize> = (0..levels.len())
+                    .filter(|i| !removed.contains(i))
+                    .map(|i| levels[i])
+                    .collect();
+                Trend::ALL
+                    .iter()
+                    .any(|&trend| kept.iter().tuple_windows().all(|(a, b)| steps.allow(trend.step(*a, *b))))
+            })
+    }
+
+    #[test]
+    fn test_parse_steps() {
+        assert_eq!("2-5".parse(), Ok(Steps { min: 2, max: 5 }));
+        assert!("0-3".parse::<Steps>().is_err());
+        assert!("4-3".parse::<Steps>().is_err());
+        assert!("3".parse::<Steps>().is_err());
+    }
+
+    #[test]
+    fn test_remove_first_level() {
+        assert!(!is_safe(&[5, 1, 2, 3], Steps::PUZZLE));
+        assert_eq!(dampening(&[5, 1, 2, 3], Steps::PUZZLE, 1), Some(vec![0]));
+    }
+
+    #[test]
+    fn test_remove_two_levels() {
+        assert!(!is_dampened(&[1, 9, 2, 9, 3], Steps::PUZZLE, 1));
+        assert!(is_dampened(&[1, 9, 2, 9, 3], Steps::PUZZLE, 2));
+    }
+
+    #[test]
+    fn test_wider_steps() {
+        assert!(!is_safe(&[1, 5, 9], Steps::PUZZLE));
+        assert!(is_safe(&[1, 5, 9], Steps { min: 2, max: 4 }));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(
+            levels in prop::collection::vec(0isize..12, 0..9),
+            min in 1isize..3,
+            extra in 0isize..3,
+            removals in 0usize..4,
+        ) {
+            let steps = Steps { min, max: min + extra };
+            prop_assert_eq!(is_dampened(&levels, steps, removals), brute_force(&levels, steps, removals));
+        }
+    }
+}
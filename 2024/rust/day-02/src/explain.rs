@@ -0,0 +1,141 @@
+use std::fmt::{self, Display};
+
+use crate::dampener::{self, Steps, Trend};
+
+/// Why two adjacent levels make a report unsafe
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reason {
+    /// The levels neither increase nor decrease
+    Equal,
+    /// The levels go against the trend set by the first two levels
+    DirectionChange,
+    /// The levels differ by less than the smallest step allowed
+    TooSmall(isize),
+    /// The levels differ by more than the largest step allowed
+    TooLarge(isize),
+}
+
+/// First pair of adjacent levels breaking the rules, by index
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Violation {
+    pub pair: (usize, usize),
+    pub reason: Reason,
+}
+
+/// Diagnostic of a report: its first violation and the levels the dampener removes to fix it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation {
+    pub violation: Option<Violation>,
+    /// Indices of the removed levels, none when removing at most the allowed number of levels is not enough
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Explanation {
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+fn reason(step: isize, steps: Steps) -> Option<Reason> {
+    match step {
+        0 => Some(Reason::Equal),
+        _ if step < 0 => Some(Reason::DirectionChange),
+        _ if step < steps.min => Some(Reason::TooSmall(step)),
+        _ if step > steps.max => Some(Reason::TooLarge(step)),
+        _ => None,
+    }
+}
+
+/// First violation of the report, the trend being set by its first two levels
+pub fn violation(levels: &[isize], steps: Steps) -> Option<Violation> {
+    let trend = match levels {
+        [a, b, ..] if a > b => Trend::Decreasing,
+        _ => Trend::Increasing,
+    };
+    levels
+        .windows(2)
+        .enumerate()
+        .find_map(|(i, pair)| {
+            let reason = reason(trend.step(pair[0], pair[1]), steps)?;
+            Some(Violation { pair: (i, i + 1), reason })
+        })
+}
+
+/// Explains whether the report is safe and how removing at most `removals` levels makes it so
+pub fn explain(levels: &[isize], steps: Steps, removals: usize) -> Explanation {
+    Explanation {
+        violation: violation(levels, steps),
+        removed: dampener::dampening(levels, steps, removals),
+    }
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::Equal => write!(f, "equal levels"),
+            Reason::DirectionChange => write!(f, "direction change"),
+            Reason::TooSmall(step) => write!(f, "step of {step} too small"),
+            Reason::TooLarge(step) => write!(f, "step of {step} too large"),
+        }
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(Violation { pair: (a, b), reason }) = self.violation else {
+            return write!(f, "safe");
+        };
+        write!(f, "unsafe at indices {a} and {b} ({reason}), ")?;
+        match &self.removed {
+            Some(removed) => {
+                let removed: Vec<String> = removed
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect();
+                write!(f, "safe without index {}", removed.join(" and "))
+            }
+            None => write!(f, "unsafe even dampened"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_safe_report() {
+        let explanation = explain(&[7, 6, 4, 2, 1], Steps::PUZZLE, 1);
+        assert!(explanation.is_safe());
+        assert_eq!(explanation.removed, Some(vec![]));
+        assert_eq!(explanation.to_string(), "safe");
+    }
+
+    #[test]
+    fn explain_step_too_large() {
+        let explanation = explain(&[1, 2, 7, 8, 9], Steps::PUZZLE, 1);
+        assert_eq!(explanation.violation, Some(Violation { pair: (1, 2), reason: Reason::TooLarge(5) }));
+        assert_eq!(explanation.to_string(), "unsafe at indices 1 and 2 (step of 5 too large), unsafe even dampened");
+    }
+
+    #[test]
+    fn explain_direction_change() {
+        let explanation = explain(&[1, 3, 2, 4, 5], Steps::PUZZLE, 1);
+        assert_eq!(explanation.violation, Some(Violation { pair: (1, 2), reason: Reason::DirectionChange }));
+        assert_eq!(explanation.to_string(), "unsafe at indices 1 and 2 (direction change), safe without index 1");
+    }
+
+    #[test]
+    fn explain_equal_levels() {
+        let explanation = explain(&[8, 6, 4, 4, 1], Steps::PUZZLE, 1);
+        assert_eq!(explanation.violation, Some(Violation { pair: (2, 3), reason: Reason::Equal }));
+        assert_eq!(explanation.removed, Some(vec![2]));
+    }
+
+    #[test]
+    fn explain_step_too_small() {
+        let explanation = explain(&[1, 3, 4], Steps { min: 2, max: 3 }, 0);
+        assert_eq!(explanation.violation, Some(Violation { pair: (1, 2), reason: Reason::TooSmall(1) }));
+        assert_eq!(explanation.removed, None);
+    }
+}
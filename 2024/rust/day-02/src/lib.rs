@@ -1,52 +1,84 @@
-use itertools::Itertools;
+use aoc_core::{Options, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-fn parse_line(line: &str) -> Vec<isize> {
+pub mod dampener;
+pub mod explain;
+
+pub use dampener::Steps;
+
+pub struct Day02;
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<isize>, ParseError> {
     line
         .split(' ')
-        .map(|s| s.parse::<isize>().unwrap())
+        .map(|s| s.parse::<isize>().map_err(|_| ParseError::at(input, s, "level")))
         .collect()
 }
 
-fn are_safe_reports(reports: &Vec<isize>) -> bool {
-    let increasing = || {
-        reports
-            .iter()
-            .tuple_windows()
-            .all(|(a, b)| a < b && b - a <= 3)
-    };
-    let decreasing = || {
-        reports
-            .iter()
-            .tuple_windows()
-            .all(|(a, b)| a > b && a - b <= 3)
-    };
-    increasing() || decreasing()
+/// Reports of the puzzle along with the rules telling them safe
+pub struct Reports {
+    pub levels: Vec<Vec<isize>>,
+    pub steps: Steps,
+    /// Levels the problem dampener may remove from a report in part 2
+    pub removals: usize,
 }
 
-pub fn part1(input: &str) -> usize {
-    input
-        .lines()
-        .map(parse_line)
-        .filter(are_safe_reports)
-        .count()
+impl Reports {
+    /// Number of reports made safe by removing at most `removals` levels
+    pub fn safe(&self, removals: usize) -> usize {
+        #[cfg(feature = "parallel")]
+        let levels = self.levels.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let levels = self.levels.iter();
+        levels
+            .filter(|levels| dampener::is_dampened(levels, self.steps, removals))
+            .count()
+    }
 }
 
-fn is_problem_dampened(reports: &Vec<isize>) -> bool {
-    let dampen = |i| {
-        let mut dampened = reports.clone();
-        dampened.remove(i);
-        are_safe_reports(&dampened)
-    };
-    (0..reports.len())
-        .any(dampen)
-}
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Reports;
+    type Answer = usize;
+
+    const OPTIONS: &'static [&'static str] = &["steps", "removals"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let levels = input
+            .lines()
+            .map(|line| parse_line(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Reports { levels, steps: Steps::PUZZLE, removals: 1 })
+    }
+
+    fn configure(mut reports: Self::Input, options: &Options) -> Result<Self::Input, String> {
+        if let Some(steps) = options.get("steps")? {
+            reports.steps = steps;
+        }
+        if let Some(removals) = options.get("removals")? {
+            reports.removals = removals;
+        }
+        Ok(reports)
+    }
 
-pub fn part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(parse_line)
-        .filter(|reports| are_safe_reports(reports) || is_problem_dampened(reports))
-        .count()
+    fn part1(reports: &Self::Input) -> usize {
+        reports.safe(0)
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        reports.safe(reports.removals)
+    }
+
+    fn explain(reports: &Self::Input) -> Option<Vec<String>> {
+        let lines = reports.levels
+            .iter()
+            .enumerate()
+            .map(|(i, levels)| format!("Report {}: {}", i + 1, explain::explain(levels, reports.steps, reports.removals)))
+            .collect();
+        Some(lines)
+    }
 }
 
 #[cfg(test)]
@@ -63,13 +95,33 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day02::part1(&Day02::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 2);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day02::part2(&Day02::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn test_explain() {
+        let lines = Day02::explain(&Day02::parse(INPUT).expect("Expecting successful parsing"))
+            .expect("Expecting an explanation");
+        assert_eq!(lines[0], "Report 1: safe");
+        assert_eq!(lines[3], "Report 4: unsafe at indices 1 and 2 (direction change), safe without index 1");
+    }
+
+    #[test]
+    fn test_configure() {
+        let options = [("steps", "1-4"), ("removals", "2")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let reports = Day02::configure(Day02::parse(INPUT).expect("Expecting successful parsing"), &options)
+            .expect("Expecting valid options");
+        assert_eq!(Day02::part1(&reports), 3);
+        assert_eq!(Day02::part2(&reports), 6);
+    }
 }
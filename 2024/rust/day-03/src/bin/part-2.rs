@@ -1,6 +1,8 @@
-use day_03::part2;
+use aoc_core::Solution;
+use day_03::Day03;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
+    let input = Day03::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day03::part2(&input));
 }
@@ -0,0 +1,136 @@
+use std::mem;
+use std::str::FromStr;
+
+/// Operation of an instruction over its two operands
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Mul,
+    Add,
+    Sub,
+}
+
+impl Op {
+    pub fn name(self) -> &'static str {
+        match self {
+            Op::Mul => "mul",
+            Op::Add => "add",
+            Op::Sub => "sub",
+        }
+    }
+
+    /// Result of the operation, none when it does not fit a `usize`
+    pub fn apply(self, a: usize, b: usize) -> Option<usize> {
+        match self {
+            Op::Mul => a.checked_mul(b),
+            Op::Add => a.checked_add(b),
+            Op::Sub => a.checked_sub(b),
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mul" => Ok(Op::Mul),
+            "add" => Ok(Op::Add),
+            "sub" => Ok(Op::Sub),
+            _ => Err("expected mul, add or sub".to_string()),
+        }
+    }
+}
+
+/// Instruction found in memory, its operands being numbers or nested instructions
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(usize),
+    Call(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn call(op: Op, lhs: Expr, rhs: Expr) -> Self {
+        Expr::Call(op, Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Value of the expression, none when an operation does not fit a `usize`
+    pub fn eval(&self) -> Option<usize> {
+        let mut pending = vec![Step::Visit(self)];
+        // Values of the operands visited, each call taking the last two of them
+        let mut values = Vec::new();
+        while let Some(step) = pending.pop() {
+            match step {
+                Step::Visit(Expr::Number(n)) => values.push(*n),
+                Step::Visit(Expr::Call(op, lhs, rhs)) => {
+                    pending.extend([Step::Apply(*op), Step::Visit(rhs), Step::Visit(lhs)]);
+                }
+                Step::Apply(op) => {
+                    let rhs = values.pop()?;
+                    let lhs = values.pop()?;
+                    values.push(op.apply(lhs, rhs)?);
+                }
+            }
+        }
+        values.pop()
+    }
+}
+
+impl Drop for Expr {
+    /// Frees the nested calls one at a time, as deep as the memory nests them
+    fn drop(&mut self) {
+        let Expr::Call(_, lhs, rhs) = self else {
+            return;
+        };
+        let mut pending = vec![mem::replace(&mut **lhs, Expr::Number(0)), mem::replace(&mut **rhs, Expr::Number(0))];
+        while let Some(mut expr) = pending.pop() {
+            if let Expr::Call(_, lhs, rhs) = &mut expr {
+                pending.push(mem::replace(&mut **lhs, Expr::Number(0)));
+                pending.push(mem::replace(&mut **rhs, Expr::Number(0)));
+            }
+        }
+    }
+}
+
+/// Pending work of the evaluation, an expression to value or an operation to apply to the
+/// values of its operands
+enum Step<'a> {
+    Visit(&'a Expr),
+    Apply(Op),
+}
+
+/// Instructions recognized in the corrupted memory
+#[derive(Clone, Debug, PartialEq)]
+pub struct Language {
+    pub ops: Vec<Op>,
+    /// Whether the operands of an instruction may be instructions themselves
+    pub nested: bool,
+}
+
+impl Default for Language {
+    /// The `mul` instructions of the puzzle, over plain numbers
+    fn default() -> Self {
+        Language { ops: vec![Op::Mul], nested: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval_nested_expression() {
+        let expr = Expr::call(Op::Mul, Expr::call(Op::Add, Expr::Number(1), Expr::Number(2)), Expr::Number(4));
+        assert_eq!(expr.eval(), Some(12));
+    }
+
+    #[test]
+    fn eval_deep_expression() {
+        let expr = (0..200_000).fold(Expr::Number(1), |expr, _| Expr::call(Op::Add, Expr::Number(1), expr));
+        assert_eq!(expr.eval(), Some(200_001));
+    }
+
+    #[test]
+    fn eval_underflow() {
+        assert_eq!(Expr::call(Op::Sub, Expr::Number(2), Expr::Number(3)).eval(), None);
+    }
+}
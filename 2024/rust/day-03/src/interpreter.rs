@@ -0,0 +1,37 @@
+use crate::instruction::Language;
+use crate::scanner::{Scanner, Token};
+
+/// Sum of the values of the instructions in memory, skipping the ones disabled by `don't`
+/// when `conditionals` holds and the ones whose value does not fit a `usize`
+pub fn run(memory: &str, language: &Language, conditionals: bool) -> usize {
+    let mut enabled = true;
+    let mut total = 0;
+    for token in Scanner::new(memory, language) {
+        match token {
+            Token::Toggle(on) if conditionals => enabled = on,
+            Token::Toggle(_) => {}
+            Token::Instruction(expr) if enabled => total += expr.eval().unwrap_or(0),
+            Token::Instruction(_) => {}
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::Op;
+
+    #[test]
+    fn run_additional_instructions() {
+        let language = Language { ops: vec![Op::Mul, Op::Add, Op::Sub], nested: false };
+        assert_eq!(run("add(1,2)sub(7,3)don't()mul(2,2)sub(1,2)", &language, false), 11);
+        assert_eq!(run("add(1,2)sub(7,3)don't()mul(2,2)sub(1,2)", &language, true), 7);
+    }
+
+    #[test]
+    fn run_nested_instructions() {
+        let language = Language { ops: vec![Op::Mul, Op::Add], nested: true };
+        assert_eq!(run("mul(add(1,2),mul(2,2))!add(1,1)", &language, true), 14);
+    }
+}
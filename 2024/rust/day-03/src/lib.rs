@@ -1,46 +1,53 @@
-mod parser {
-    use nom::branch::alt;
-    use nom::bytes::complete::tag;
-    use nom::character::complete::{char, usize};
-    use nom::combinator::map;
-    use nom::sequence::delimited;
-    use nom::{IResult, Parser};
+use aoc_core::{Options, ParseError, Solution};
 
-    fn operands_pair(input: &str) -> IResult<&str, (usize, usize)> {
-        let pnumbers = (usize, tag(","), usize);
-        map(pnumbers, |(lhs, _, rhs)| (lhs, rhs)).parse(input)
-    }
+pub mod instruction;
+pub mod interpreter;
+pub mod parser;
+pub mod scanner;
 
-    pub fn mul(input: &str) -> IResult<&str, usize> {
-        let pmul = delimited(tag("mul("), operands_pair, char(')'));
-        map(pmul, |(a, b)| a * b).parse(input)
-    }
+pub use instruction::{Expr, Language, Op};
 
-    pub fn do_dont(input: &str) -> IResult<&str, bool> {
-        let pdodont = alt((tag("don't"), tag("do")));
-        map(pdodont, |dodont| dodont != "don't").parse(input)
-    }
-}
+pub struct Day03;
 
-fn multiplications(s: &str, total: usize) -> usize {
-    if s.is_empty() { total }
-    else if let Ok((remaining, v)) = parser::mul(s) { multiplications(remaining, total + v) }
-    else { multiplications(&s[1..], total) }
+/// Corrupted memory of the puzzle along with the instructions it is read with
+pub struct Program {
+    pub memory: String,
+    pub language: Language,
 }
 
-pub fn part1(input: &str) -> usize {
-    multiplications(input, 0)
-}
+impl Solution for Day03 {
+    const DAY: u8 = 3;
 
-fn multiplications_with_do_dont(s: &str, total: usize, apply: bool) -> usize {
-    if s.is_empty() { total }
-    else if let Ok((remaining, new_apply)) = parser::do_dont(s) { multiplications_with_do_dont(remaining, total, new_apply) }
-    else if let Ok((remaining, v)) = parser::mul(s) { multiplications_with_do_dont(remaining, total + if apply { v } else { 0 }, apply) }
-    else { multiplications_with_do_dont(&s[1..], total, apply) }
-}
+    type Input = Program;
+    type Answer = usize;
 
-pub fn part2(input: &str) -> usize {
-    multiplications_with_do_dont(input, 0, true)
+    const OPTIONS: &'static [&'static str] = &["instructions", "nested"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Program { memory: input.to_string(), language: Language::default() })
+    }
+
+    fn configure(mut program: Self::Input, options: &Options) -> Result<Self::Input, String> {
+        if let Some(instructions) = options.get::<String>("instructions")? {
+            program.language.ops = instructions
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Invalid instructions \"{instructions}\": {e}"))?;
+        }
+        if let Some(nested) = options.get("nested")? {
+            program.language.nested = nested;
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Input) -> usize {
+        interpreter::run(&program.memory, &program.language, false)
+    }
+
+    fn part2(program: &Self::Input) -> usize {
+        interpreter::run(&program.memory, &program.language, true)
+    }
 }
 
 #[cfg(test)]
@@ -49,13 +56,43 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+        let result = Day03::part1(&Day03::parse("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))").expect("Expecting successful parsing"));
         assert_eq!(result, 161);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
+        let result = Day03::part2(&Day03::parse("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))").expect("Expecting successful parsing"));
         assert_eq!(result, 48);
     }
+
+    #[test]
+    fn test_configure() {
+        let options = [("instructions", "mul,add"), ("nested", "true")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let program = Day03::configure(Day03::parse("mul(add(1,2),3)add(1,1)").expect("Expecting successful parsing"), &options)
+            .expect("Expecting valid options");
+        assert_eq!(Day03::part1(&program), 11);
+    }
+
+    #[test]
+    fn test_deeply_nested() {
+        let options = [("instructions", "add,mul"), ("nested", "true")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let memory = format!("{}0{}mul(2,3)", "add(1,".repeat(200_000), ")".repeat(200_000));
+        let program = Day03::configure(Day03::parse(&memory).expect("Expecting successful parsing"), &options)
+            .expect("Expecting valid options");
+        assert_eq!(Day03::part1(&program), 200_006);
+    }
+
+    #[test]
+    fn test_invalid_instructions() {
+        let options = [("instructions".to_string(), "mul,div".to_string())].into_iter().collect();
+        let result = Day03::configure(Day03::parse("").expect("Expecting successful parsing"), &options);
+        assert_eq!(result.err(), Some("Invalid instructions \"mul,div\": expected mul, add or sub".to_string()));
+    }
 }
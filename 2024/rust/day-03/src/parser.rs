@@ -0,0 +1,93 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, usize};
+use nom::combinator::map;
+use nom::error::{Error, ErrorKind};
+use nom::sequence::{delimited, terminated};
+use nom::{IResult, Parser};
+
+use crate::instruction::{Expr, Language, Op};
+
+fn operands_pair(input: &str) -> IResult<&str, (usize, usize)> {
+    let pnumbers = (usize, tag(","), usize);
+    map(pnumbers, |(lhs, _, rhs)| (lhs, rhs)).parse(input)
+}
+
+fn call<'a>(name: &'static str, input: &'a str) -> IResult<&'a str, (usize, usize)> {
+    delimited((tag(name), char('(')), operands_pair, char(')')).parse(input)
+}
+
+pub fn mul(input: &str) -> IResult<&str, (usize, usize)> {
+    call("mul", input)
+}
+
+pub fn add(input: &str) -> IResult<&str, (usize, usize)> {
+    call("add", input)
+}
+
+pub fn sub(input: &str) -> IResult<&str, (usize, usize)> {
+    call("sub", input)
+}
+
+pub fn number(input: &str) -> IResult<&str, usize> {
+    usize(input)
+}
+
+pub fn do_dont(input: &str) -> IResult<&str, bool> {
+    let pdodont = alt((tag("don't"), tag("do")));
+    map(pdodont, |dodont| dodont != "don't").parse(input)
+}
+
+/// Instruction of the language over plain numbers, e.g. `mul(2,4)`
+pub fn instruction<'a>(language: &Language, input: &'a str) -> IResult<&'a str, Expr> {
+    for &op in &language.ops {
+        let pop = match op {
+            Op::Mul => mul,
+            Op::Add => add,
+            Op::Sub => sub,
+        };
+        if let Ok((rest, (a, b))) = pop(input) {
+            return Ok((rest, Expr::call(op, Expr::Number(a), Expr::Number(b))));
+        }
+    }
+    Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)))
+}
+
+/// Name of an instruction of the language along with its opening parenthesis, e.g. `mul(`,
+/// starting an instruction whose operands may be instructions themselves
+pub fn opening<'a>(language: &Language, input: &'a str) -> IResult<&'a str, Op> {
+    for &op in &language.ops {
+        let popening: IResult<&str, &str> = terminated(tag(op.name()), char('(')).parse(input);
+        if let Ok((rest, _)) = popening {
+            return Ok((rest, op));
+        }
+    }
+    Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mul() {
+        assert_eq!(mul("mul(2,4)x"), Ok(("x", (2, 4))));
+        assert!(mul("mul(2,4]").is_err());
+    }
+
+    #[test]
+    fn parse_instruction_like_mul() {
+        let result = instruction(&Language::default(), "mul(2,4)x");
+        assert_eq!(result, Ok(("x", Expr::call(Op::Mul, Expr::Number(2), Expr::Number(4)))));
+        assert!(instruction(&Language::default(), "add(2,4)").is_err());
+        assert!(instruction(&Language::default(), "mul(add(1,2),4)").is_err());
+    }
+
+    #[test]
+    fn parse_opening() {
+        let language = Language { ops: vec![Op::Mul, Op::Sub], nested: true };
+        assert_eq!(opening(&language, "sub(1,2)"), Ok(("1,2)", Op::Sub)));
+        assert!(opening(&language, "add(1,2)").is_err());
+        assert!(opening(&language, "mul[1,2)").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+use crate::instruction::{Expr, Language, Op};
+use crate::parser;
+
+/// Meaningful piece of the corrupted memory
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    /// `do` when true, `don't` when false
+    Toggle(bool),
+    Instruction(Expr),
+}
+
+/// Instruction opened by its name and parenthesis, its operands being read
+struct Frame {
+    op: Op,
+    operands: Vec<Expr>,
+    comma: bool,
+}
+
+impl Frame {
+    fn new(op: Op) -> Self {
+        Self { op, operands: Vec::with_capacity(2), comma: false }
+    }
+
+    fn expects_operand(&self) -> bool {
+        self.operands.is_empty() || (self.comma && self.operands.len() == 1)
+    }
+}
+
+/// Iterates over the tokens of the memory, skipping the corrupted characters between them
+pub struct Scanner<'a> {
+    rest: &'a str,
+    language: &'a Language,
+    /// Nested instructions opened and not closed yet, outermost first
+    open: Vec<Frame>,
+    /// Complete instructions read inside open ones that turned out corrupted, yet to be returned
+    salvaged: VecDeque<Expr>,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(memory: &'a str, language: &'a Language) -> Self {
+        Self { rest: memory, language, open: Vec::new(), salvaged: VecDeque::new() }
+    }
+
+    /// Reads the next piece of the innermost open instruction, giving the outermost one once
+    /// it closes
+    fn step(&mut self) -> Option<Expr> {
+        let frame = self.open.last_mut().expect("Expecting an open instruction");
+        if frame.expects_operand() {
+            if let Ok((rest, n)) = parser::number(self.rest) {
+                self.rest = rest;
+                frame.operands.push(Expr::Number(n));
+            } else if let Ok((rest, expr)) = parser::instruction(self.language, self.rest) {
+                self.rest = rest;
+                frame.operands.push(expr);
+            } else if let Ok((rest, op)) = parser::opening(self.language, self.rest) {
+                self.rest = rest;
+                self.open.push(Frame::new(op));
+            } else {
+                self.abandon();
+            }
+            return None;
+        }
+        let (expected, closing) = if frame.comma { (')', true) } else { (',', false) };
+        let Some(rest) = self.rest.strip_prefix(expected) else {
+            self.abandon();
+            return None;
+        };
+        self.rest = rest;
+        if !closing {
+            frame.comma = true;
+            return None;
+        }
+        let mut frame = self.open.pop().expect("Expecting an open instruction");
+        let rhs = frame.operands.pop().expect("Expecting a right operand");
+        let lhs = frame.operands.pop().expect("Expecting a left operand");
+        let expr = Expr::call(frame.op, lhs, rhs);
+        match self.open.last_mut() {
+            Some(parent) => {
+                parent.operands.push(expr);
+                None
+            },
+            None => Some(expr),
+        }
+    }
+
+    /// Gives up the open instructions, corrupted at the current position, keeping the complete
+    /// instructions read inside them as tokens of their own, so the scan never goes back
+    fn abandon(&mut self) {
+        let calls = self.open
+            .drain(..)
+            .flat_map(|frame| frame.operands)
+            .filter(|expr| matches!(expr, Expr::Call(..)));
+        self.salvaged.extend(calls);
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            if let Some(expr) = self.salvaged.pop_front() {
+                return Some(Token::Instruction(expr));
+            }
+            if !self.open.is_empty() {
+                if let Some(expr) = self.step() {
+                    return Some(Token::Instruction(expr));
+                }
+                continue;
+            }
+            let c = self.rest.chars().next()?;
+            if let Ok((rest, enabled)) = parser::do_dont(self.rest) {
+                self.rest = rest;
+                return Some(Token::Toggle(enabled));
+            }
+            if let Ok((rest, expr)) = parser::instruction(self.language, self.rest) {
+                self.rest = rest;
+                return Some(Token::Instruction(expr));
+            }
+            match parser::opening(self.language, self.rest) {
+                Ok((rest, op)) if self.language.nested => {
+                    self.rest = rest;
+                    self.open.push(Frame::new(op));
+                },
+                _ => self.rest = &self.rest[c.len_utf8()..],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested(ops: &[Op]) -> Language {
+        Language { ops: ops.to_vec(), nested: true }
+    }
+
+    #[test]
+    fn scan_tokens() {
+        let language = Language::default();
+        let tokens: Vec<Token> = Scanner::new("xmul(2,4)don't()é_mul(5,5)", &language).collect();
+        assert_eq!(tokens, vec![
+            Token::Instruction(Expr::call(Op::Mul, Expr::Number(2), Expr::Number(4))),
+            Token::Toggle(false),
+            Token::Instruction(Expr::call(Op::Mul, Expr::Number(5), Expr::Number(5))),
+        ]);
+    }
+
+    #[test]
+    fn scan_long_memory() {
+        let memory = "mul(1,1)?".repeat(200_000);
+        let language = Language::default();
+        assert_eq!(Scanner::new(&memory, &language).count(), 200_000);
+    }
+
+    #[test]
+    fn scan_nested_instruction() {
+        let language = nested(&[Op::Mul, Op::Add]);
+        let tokens: Vec<Token> = Scanner::new("mul(add(1,2),4)do()", &language).collect();
+        let expected = Expr::call(Op::Mul, Expr::call(Op::Add, Expr::Number(1), Expr::Number(2)), Expr::Number(4));
+        assert_eq!(tokens, vec![Token::Instruction(expected), Token::Toggle(true)]);
+    }
+
+    #[test]
+    fn scan_inside_corrupted_instruction() {
+        let language = nested(&[Op::Mul, Op::Add]);
+        let tokens: Vec<Token> = Scanner::new("mul(add(mul(1,2),add(3,4)),5]mul(6,7)", &language).collect();
+        assert_eq!(tokens, vec![
+            Token::Instruction(Expr::call(
+                Op::Add,
+                Expr::call(Op::Mul, Expr::Number(1), Expr::Number(2)),
+                Expr::call(Op::Add, Expr::Number(3), Expr::Number(4)),
+            )),
+            Token::Instruction(Expr::call(Op::Mul, Expr::Number(6), Expr::Number(7))),
+        ]);
+        let tokens: Vec<Token> = Scanner::new("mul(2,add(1,sub(3,1)mul(1,1)", &nested(&[Op::Mul, Op::Add, Op::Sub])).collect();
+        assert_eq!(tokens, vec![
+            Token::Instruction(Expr::call(Op::Sub, Expr::Number(3), Expr::Number(1))),
+            Token::Instruction(Expr::call(Op::Mul, Expr::Number(1), Expr::Number(1))),
+        ]);
+    }
+
+    #[test]
+    fn scan_deep_memory() {
+        let language = nested(&[Op::Add]);
+        let unclosed = "add(1,".repeat(200_000);
+        assert_eq!(Scanner::new(&unclosed, &language).count(), 0);
+        let closed = format!("{unclosed}0{}", ")".repeat(200_000));
+        let tokens: Vec<Token> = Scanner::new(&closed, &language).collect();
+        match tokens.as_slice() {
+            [Token::Instruction(expr)] => assert_eq!(expr.eval(), Some(200_000)),
+            _ => panic!("Expecting a single instruction"),
+        }
+    }
+}
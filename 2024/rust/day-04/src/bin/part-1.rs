@@ -1,6 +1,8 @@
-use day_04::part1;
+use aoc_core::Solution;
+use day_04::Day04;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content))
-}
\ No newline at end of file
+    let input = Day04::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day04::part1(&input));
+}
@@ -1,77 +1,70 @@
-/// (x, y) directions
-const DIRECTIONS: [(isize, isize); 8] = [
-    ( 0, -1), // Top
-    (-1, -1), // Top left
-    (-1,  0), // Left
-    (-1,  1), // Bottom left
-    ( 0,  1), // Bottom
-    ( 1,  1), // Bottom right
-    ( 1,  0), // Right
-    ( 1, -1), // Top right
-];
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point, COMPASS};
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
-}
-
-fn count_xmas(chars: &Vec<Vec<char>>, x: usize, y: usize) -> usize {
-    let in_bounds = |(dx, dy): &&(isize, isize)| {
-        let end_x = x as isize + 3 * dx;
-        let end_y = y as isize + 3 * dy;
-        0 <= end_x && end_x < chars[0].len() as isize && 0 <= end_y && end_y < chars.len() as isize
-    };
+pub struct Day04;
 
-    let has_xmas = |(dx, dy): &&(isize, isize)| {
+fn count_xmas(chars: &Grid<char>, p: Point) -> usize {
+    let has_xmas = |&&(dx, dy): &&(isize, isize)| {
         "XMAS"
-            .char_indices()
-            .all(|(i, c)| {
-                let col = (x as isize + i as isize * dx) as usize;
-                let row = (y as isize + i as isize * dy) as usize;
-                c == chars[row][col]
-            })
+            .chars()
+            .enumerate()
+            .all(|(i, c)| chars
+                .offset(p, i as isize * dx, i as isize * dy)
+                .is_some_and(|q| chars[q] == c))
     };
 
-    DIRECTIONS
+    COMPASS
         .iter()
-        .filter(in_bounds)
         .filter(has_xmas)
         .count()
 }
 
-pub fn part1(input: &str) -> usize {
-    let chars = parse_input(input);
-    let height = chars.len();
-    let width = chars[0].len();
-    (0..height)
-        .flat_map(|y| (0..width).map(|x| (x, y)).collect::<Vec<(usize, usize)>>())
-        .filter(|(x, y)| chars[*y][*x] == 'X')
-        .map(|(x, y)| count_xmas(&chars, x, y))
+fn xmas_count(chars: &Grid<char>) -> usize {
+    chars
+        .points()
+        .filter(|&p| chars[p] == 'X')
+        .map(|p| count_xmas(chars, p))
         .sum()
 }
 
-fn has_x_mas(chars: &Vec<Vec<char>>, x: usize, y: usize) -> bool {
-    if chars[y][x] != 'A' { false } else {
-        let tl = chars[y - 1][x - 1];
-        let tr = chars[y - 1][x + 1];
-        let bl = chars[y + 1][x - 1];
-        let br = chars[y + 1][x + 1];
-        ((tl == 'M' && br == 'S') || (tl == 'S' && br == 'M')) && ((tr == 'M' && bl == 'S') || (tr == 'S' && bl == 'M'))
+fn has_x_mas(chars: &Grid<char>, p: Point) -> bool {
+    let corner = |dx, dy| chars.offset(p, dx, dy).map(|q| chars[q]);
+    if chars[p] != 'A' { false } else {
+        let tl = corner(-1, -1);
+        let tr = corner( 1, -1);
+        let bl = corner(-1,  1);
+        let br = corner( 1,  1);
+        let mas = |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+        mas(tl, br) && mas(tr, bl)
     }
 }
 
-pub fn part2(input: &str) -> usize {
-    let chars = parse_input(input);
-    let height = chars.len();
-    let width = chars[0].len();
-    (1..height - 1)
-        .flat_map(|y| (1..width - 1).map(|x| (x, y)).collect::<Vec<(usize, usize)>>())
-        .filter(|(x, y)| has_x_mas(&chars, *x, *y))
+fn x_mas_count(chars: &Grid<char>) -> usize {
+    chars
+        .points()
+        .filter(|&p| has_x_mas(chars, p))
         .count()
 }
 
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "letter", Some)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        xmas_count(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        x_mas_count(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,7 +82,7 @@ S.S.S.S.SS
 ..M.M.M.MM
 .X.X.XMASX
 ";
-        let result = part1(input);
+        let result = Day04::part1(&Day04::parse(input).expect("Expecting successful parsing"));
         assert_eq!(result, 18);
     }
 
@@ -106,7 +99,7 @@ S.S.S.S.S.
 M.M.M.M.M.
 ..........
 ";
-        let result = part2(input);
+        let result = Day04::part2(&Day04::parse(input).expect("Expecting successful parsing"));
         assert_eq!(result, 9);
     }
 }
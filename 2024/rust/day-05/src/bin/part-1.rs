@@ -1,6 +1,8 @@
-use day_05::part1;
+use aoc_core::Solution;
+use day_05::Day05;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
+    let input = Day05::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day05::part1(&input));
 }
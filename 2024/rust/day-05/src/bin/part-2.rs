@@ -1,6 +1,8 @@
-use day_05::part2;
+use aoc_core::Solution;
+use day_05::Day05;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
+    let input = Day05::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day05::part2(&input));
 }
@@ -1,60 +1,66 @@
 use std::collections::{HashMap, HashSet};
 
+use aoc_core::{ParseError, Solution};
+
+pub struct Day05;
+
+type Rule = (usize, usize);
+type Update = Vec<usize>;
+
 mod parser {
+    use super::{Rule, Update};
+    use aoc_core::{parser::{finish, IResult}, ParseError};
     use nom::{
-        character::complete::{char, newline, usize},
-        combinator::eof,
-        multi::{many1, separated_list1},
-        sequence::{separated_pair, terminated}, IResult, Parser
+        character::complete::{char, usize},
+        combinator::all_consuming,
+        multi::separated_list1,
+        sequence::separated_pair,
+        Parser
     };
 
-    fn parse_rule(input: &str) -> IResult<&str, (usize, usize)> {
+    fn parse_rule(input: &str) -> IResult<'_, Rule> {
         separated_pair(usize, char('|'), usize).parse(input)
     }
 
-    fn parse_rules(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
-        let prule_nl = terminated(parse_rule, newline);
-        terminated(many1(prule_nl), newline).parse(input)
-    }
-
-    fn parse_update(input: &str) -> IResult<&str, Vec<usize>> {
+    fn parse_update(input: &str) -> IResult<'_, Update> {
         separated_list1(char(','), usize).parse(input)
     }
 
-    fn parse_updates(input: &str) -> IResult<&str, Vec<Vec<usize>>> {
-        let pupdate_nl = terminated(parse_update, newline);
-        many1(pupdate_nl).parse(input)
-    }
-
-    pub fn parse_input(input: &str) -> IResult<&str, (Vec<(usize, usize)>, Vec<Vec<usize>>)> {
-        let (input, rules) = parse_rules(input)?;
-        let (input, updates) = parse_updates(input)?;
-        eof(input)?;
-        Ok((input, (rules, updates)))
+    pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+        let mut lines = input.lines();
+        let rules = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|line| finish(input, all_consuming(parse_rule).parse(line)))
+            .collect::<Result<_, _>>()?;
+        let updates = lines
+            .map(|line| finish(input, all_consuming(parse_update).parse(line)))
+            .collect::<Result<_, _>>()?;
+        Ok((rules, updates))
     }
 }
 
-fn process_rules(rules: &Vec<(usize, usize)>) -> (HashMap<usize, HashSet<usize>>, HashMap<usize, HashSet<usize>>) {
+fn process_rules(rules: &[Rule]) -> (HashMap<usize, HashSet<usize>>, HashMap<usize, HashSet<usize>>) {
     let mut pages_before: HashMap<usize, HashSet<usize>> = HashMap::new();
     let mut pages_after: HashMap<usize, HashSet<usize>> = HashMap::new();
     for (a, b) in rules {
         pages_before
             .entry(*b)
-            .or_insert(HashSet::new())
+            .or_default()
             .insert(*a);
         pages_after
             .entry(*a)
-            .or_insert(HashSet::new())
+            .or_default()
             .insert(*b);
     }
     (pages_before, pages_after)
 }
 
-fn check_pages_before(pages_before: &HashMap<usize, HashSet<usize>>, update: &Vec<usize>, i: usize) -> bool {
+fn check_pages_before(pages_before: &HashMap<usize, HashSet<usize>>, update: &[usize], i: usize) -> bool {
     let p = update[i];
     pages_before
         .get(&p)
-        .map_or(true, |before| {
+        .is_none_or(|before| {
             if i > 0 {
                 update[..i]
                     .iter()
@@ -63,11 +69,11 @@ fn check_pages_before(pages_before: &HashMap<usize, HashSet<usize>>, update: &Ve
         })
 }
 
-fn check_pages_after(pages_after: &HashMap<usize, HashSet<usize>>, update: &Vec<usize>, i: usize) -> bool {
+fn check_pages_after(pages_after: &HashMap<usize, HashSet<usize>>, update: &[usize], i: usize) -> bool {
     let p = update[i];
     pages_after
         .get(&p)
-        .map_or(true, |after| {
+        .is_none_or(|after| {
             if i < update.len() - 1 {
                 update[(i+1)..]
                     .iter()
@@ -76,53 +82,45 @@ fn check_pages_after(pages_after: &HashMap<usize, HashSet<usize>>, update: &Vec<
         })
 }
 
-fn check_ordering(pages_before: &HashMap<usize, HashSet<usize>>, pages_after: &HashMap<usize, HashSet<usize>>, update: &Vec<usize>) -> bool {
+fn check_ordering(pages_before: &HashMap<usize, HashSet<usize>>, pages_after: &HashMap<usize, HashSet<usize>>, update: &[usize]) -> bool {
     (0..update.len())
-        .all(|i| check_pages_before(&pages_before, &update, i) && check_pages_after(&pages_after, &update, i))
+        .all(|i| check_pages_before(pages_before, update, i) && check_pages_after(pages_after, update, i))
 }
 
-fn mid(v: &Vec<usize>) -> usize {
-    v[(v.len() / 2) as usize]
+fn mid(v: &[usize]) -> usize {
+    v[v.len() / 2]
 }
 
-pub fn part1(input: &str) -> usize {
-    let (_, (rules, updates)) = parser::parse_input(input).unwrap();
-    let (pages_before, pages_after) = process_rules(&rules);
+fn ordered_updates_score(rules: &[Rule], updates: &[Update]) -> usize {
+    let (pages_before, pages_after) = process_rules(rules);
     
     updates
         .iter()
         .filter(|update| check_ordering(&pages_before, &pages_after, update))
-        .map(mid)
+        .map(|update| mid(update))
         .sum()
 }
 
-fn swap(v: &mut Vec<usize>, i: usize, j: usize) {
-    let tmp = v[i];
-    v[i] = v[j];
-    v[j] = tmp;
-}
-
-fn fix_update(pages_before: &HashMap<usize, HashSet<usize>>, pages_after: &HashMap<usize, HashSet<usize>>, update: &Vec<usize>) -> Vec<usize> {
-    let mut fixed = update.clone();
+fn fix_update(pages_before: &HashMap<usize, HashSet<usize>>, pages_after: &HashMap<usize, HashSet<usize>>, update: &[usize]) -> Vec<usize> {
+    let mut fixed = update.to_vec();
     for i in 0..fixed.len() {
         let mut j = i;
         while j < fixed.len() - 1 && !check_pages_after(pages_after, &fixed, j) {
-            swap(&mut fixed, j, j + 1);
+            fixed.swap(j, j + 1);
             j += 1;
         }
     
         let mut j = i;
         while j > 0 && !check_pages_before(pages_before, &fixed, j) {
-            swap(&mut fixed, j - 1, j);
+            fixed.swap(j - 1, j);
             j -= 1;
         }
     }
     fixed
 }
 
-pub fn part2(input: &str) -> usize {
-    let (_, (rules, updates)) = parser::parse_input(input).unwrap();
-    let (pages_before, pages_after) = process_rules(&rules);
+fn fixed_updates_score(rules: &[Rule], updates: &[Update]) -> usize {
+    let (pages_before, pages_after) = process_rules(rules);
     
     updates
         .iter()
@@ -132,6 +130,25 @@ pub fn part2(input: &str) -> usize {
         .sum()
 }
 
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Rule>, Vec<Update>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> usize {
+        ordered_updates_score(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> usize {
+        fixed_updates_score(rules, updates)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,13 +185,19 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day05::part1(&Day05::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 143);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day05::part2(&Day05::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 123);
     }
+
+    #[test]
+    fn test_invalid_update() {
+        let result = Day05::parse("47|53\n97|13\n\n75,47\n97;61\n");
+        assert_eq!(result.err(), Some(ParseError::new(5, 3, ";61", "end of input")));
+    }
 }
@@ -1,6 +1,8 @@
-use day_06::part1;
+use aoc_core::Solution;
+use day_06::Day06;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
+    let input = Day06::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day06::part1(&input));
 }
@@ -1,6 +1,8 @@
-use day_06::part2;
+use aoc_core::Solution;
+use day_06::Day06;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
+    let input = Day06::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day06::part2(&input));
 }
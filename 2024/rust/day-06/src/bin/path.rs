@@ -0,0 +1,38 @@
+use std::{fs, path::PathBuf};
+
+use aoc_core::Solution;
+use clap::Parser;
+use day_06::{color, loop_obstructions, render, walk, Day06};
+
+/// Draws the path of the day 6 guard and the obstructions trapping it in a loop
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, the committed input when omitted
+    input: Option<PathBuf>,
+
+    /// Writes a PPM image to this file instead of printing text
+    #[arg(short, long)]
+    image: Option<PathBuf>,
+
+    /// Pixels per tile of the image
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+}
+
+fn main() -> Result<(), String> {
+    let args = Args::parse();
+    let content = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?,
+        None => include_str!("../../input.txt").to_string(),
+    };
+    let (map, guard) = Day06::parse(&content).map_err(|e| e.to_string())?;
+    let drawing = render(&map, &walk(&map, guard), &loop_obstructions(&map, guard));
+    match args.image {
+        Some(path) => fs::write(&path, drawing.ppm(args.scale, |_, &tile| color(tile)))
+            .map_err(|e| format!("Cannot write {}: {e}", path.display())),
+        None => {
+            print!("{}", drawing.render(|_, &tile| tile));
+            Ok(())
+        },
+    }
+}
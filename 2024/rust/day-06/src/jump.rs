@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use grid::{Direction, Grid, Point};
+
+use crate::Map;
+
+/// Tile where the guard stops walking straight from every tile and direction,
+/// `None` when the guard walks off the map instead
+pub struct JumpTable {
+    stops: Grid<[Option<Point>; 4]>,
+}
+
+/// Steps walked from `from` to reach `to` facing `dir`, `None` when `to` is not ahead
+fn distance(from: Point, to: Point, dir: Direction) -> Option<usize> {
+    match dir {
+        Direction::Up    if from.x == to.x && to.y <= from.y => Some(from.y - to.y),
+        Direction::Down  if from.x == to.x && to.y >= from.y => Some(to.y - from.y),
+        Direction::Left  if from.y == to.y && to.x <= from.x => Some(from.x - to.x),
+        Direction::Right if from.y == to.y && to.x >= from.x => Some(to.x - from.x),
+        _ => None,
+    }
+}
+
+impl JumpTable {
+    pub fn new(map: &Map) -> Self {
+        let mut stops = Grid::new(map.width(), map.height(), [None; 4]);
+        // Row-major order visits the tile above and the tile on the left first
+        let points: Vec<Point> = map.points().collect();
+        for dir in Direction::ALL {
+            let ordered: Box<dyn Iterator<Item = &Point>> = match dir {
+                Direction::Up | Direction::Left => Box::new(points.iter()),
+                Direction::Down | Direction::Right => Box::new(points.iter().rev()),
+            };
+            for &p in ordered {
+                stops[p][dir as usize] = match map.step(p, dir) {
+                    None => None,
+                    Some(next) if map[next] == '#' => Some(p),
+                    Some(next) => stops[next][dir as usize],
+                };
+            }
+        }
+        Self { stops }
+    }
+
+    /// Tile where the guard stops walking from `p` facing `dir`, once `obstruction` is added
+    pub fn stop(&self, p: Point, dir: Direction, obstruction: Point) -> Option<Point> {
+        let stop = self.stops[p][dir as usize];
+        let walked = stop.map_or(usize::MAX, |s| distance(p, s, dir).expect("Expecting stop ahead"));
+        match distance(p, obstruction, dir) {
+            Some(d) if d > 0 && d <= walked => obstruction.step(dir.opposite()),
+            _ => stop,
+        }
+    }
+
+    /// Whether the guard at `p` facing `dir` ends up walking in circles once `obstruction` is added
+    pub fn has_loop(&self, mut p: Point, mut dir: Direction, obstruction: Point) -> bool {
+        let mut turns = HashSet::new();
+        while turns.insert((p, dir)) {
+            match self.stop(p, dir, obstruction) {
+                Some(stop) => {
+                    p = stop;
+                    dir = dir.turn_right();
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+..#.
+....
+#..#
+.#..
+";
+
+    #[test]
+    fn stops() {
+        let map = Grid::parse(MAP, "map tile", Some).expect("Expecting successful parsing");
+        let jumps = JumpTable::new(&map);
+        let far = Point::new(3, 3);
+        assert_eq!(jumps.stop(Point::new(2, 3), Direction::Up, far), Some(Point::new(2, 1)));
+        assert_eq!(jumps.stop(Point::new(3, 0), Direction::Down, far), Some(Point::new(3, 1)));
+        assert_eq!(jumps.stop(Point::new(2, 2), Direction::Left, far), Some(Point::new(1, 2)));
+        assert_eq!(jumps.stop(Point::new(0, 1), Direction::Right, far), None);
+    }
+
+    #[test]
+    fn stops_at_obstruction() {
+        let map = Grid::parse(MAP, "map tile", Some).expect("Expecting successful parsing");
+        let jumps = JumpTable::new(&map);
+        let from = Point::new(0, 1);
+        assert_eq!(jumps.stop(from, Direction::Right, Point::new(2, 1)), Some(Point::new(1, 1)));
+        assert_eq!(jumps.stop(from, Direction::Right, Point::new(0, 1)), None, "Obstruction behind the guard");
+        assert_eq!(jumps.stop(from, Direction::Right, Point::new(2, 0)), None, "Obstruction off the row");
+    }
+}
@@ -1,92 +1,88 @@
 use std::collections::HashSet;
 
-const DIRECTIONS: [(isize, isize); 4] = [
-    ( 0, -1), // Up
-    ( 1,  0), // Right
-    ( 0,  1), // Down
-    (-1,  0), // Left
-];
-
-fn parse_input(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
-    let map =
-        input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
-
-    let mut guard = (0, 0);
-    for row in 0..map.len() {
-        for col in 0..map[0].len() {
-            if map[row][col] == '^' {
-                guard = (row, col);
-            }
-        }
-    }
+use aoc_core::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+mod jump;
+mod path;
+
+use jump::JumpTable;
+pub use path::{color, render, walk, Step};
 
-    (map, guard)
+pub struct Day06;
+
+pub type Map = Grid<char>;
+
+fn parse_input(input: &str) -> Result<(Map, Point), ParseError> {
+    let map = Grid::parse(input, "map tile", Some)?;
+    let guard = map
+        .position(|&c| c == '^')
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "guard '^'"))?;
+    Ok((map, guard))
 }
 
-fn next_point(map: &Vec<Vec<char>>, (row, col): (usize, usize), dir: usize) -> Option<(usize, usize)> {
-    let (dx, dy) = DIRECTIONS[dir];
-    let height = map.len() as isize;
-    let width = map[0].len() as isize;
-    let next_row = row as isize + dy;
-    let next_col = col as isize + dx;
-    if 0 <= next_row && next_row < height && 0 <= next_col && next_col < width {
-        Some((next_row as usize, next_col as usize))
-    } else {
-        None
-    }
+fn simulate_path(map: &Map, initial_position: Point) -> HashSet<Point> {
+    walk(map, initial_position)
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect()
 }
 
-fn simulate_path(map: &Vec<Vec<char>>, initial_position: (usize, usize)) -> HashSet<(usize, usize)> {
-    let mut path = HashSet::new();
+/// Every tile of the path where an obstruction could be placed, along with where the guard
+/// stands and faces when first running into it
+fn obstruction_candidates(map: &Map, initial_position: Point) -> Vec<(Point, Direction, Point)> {
+    let mut candidates = Vec::new();
+    let mut tried = HashSet::from([initial_position]);
     let mut guard = initial_position;
-    let mut dir = 0;
-    path.insert(initial_position);
-    while let Some(p) = next_point(map, guard, dir) {
-        if map[p.0][p.1] == '#' {
-            dir = (dir + 1) % 4;
+    let mut dir = Direction::Up;
+    while let Some(p) = map.step(guard, dir) {
+        if map[p] == '#' {
+            dir = dir.turn_right();
         } else {
-            path.insert(p);
+            if tried.insert(p) {
+                candidates.push((guard, dir, p));
+            }
             guard = p;
         }
     }
-    path
+    candidates
 }
 
-pub fn part1(input: &str) -> usize {
-    let (map, guard) = parse_input(input);
-    let path = simulate_path(&map, guard);
-    path.len()
+/// Tiles where a new obstruction traps the guard in a loop, in the order the guard reaches them
+pub fn loop_obstructions(map: &Map, initial_position: Point) -> Vec<Point> {
+    let jumps = JumpTable::new(map);
+    let candidates = obstruction_candidates(map, initial_position);
+    #[cfg(feature = "parallel")]
+    let candidates = candidates.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let candidates = candidates.iter();
+    // The guard walks the original path until first running into the obstruction
+    candidates
+        .filter(|&&(p, dir, obstruction)| jumps.has_loop(p, dir, obstruction))
+        .map(|&(_, _, obstruction)| obstruction)
+        .collect()
 }
 
-fn has_loop(map: &Vec<Vec<char>>, obstruction: (usize, usize), initial_position: (usize, usize)) -> bool {
-    let mut guard = initial_position;
-    let mut dir = 0;
-    let mut visited = HashSet::new();
-    visited.insert((initial_position, dir));
-    while let Some(p) = next_point(map, guard, dir) {
-        if map[p.0][p.1] == '#' || p == obstruction {
-            dir = (dir + 1) % 4;
-        } else {
-            if !visited.insert((p, dir)) {
-                return true;
-            }
-            guard = p;
-        }
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (Map, Point);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
-    false
-}
 
-pub fn part2(input: &str) -> usize {
-    let (map, guard) = parse_input(input);
-    let mut path = simulate_path(&map, guard);
-    path.remove(&guard);
-    path
-        .iter()
-        .filter(|&&p| has_loop(&map, p, guard))
-        .count()
+    fn part1((map, guard): &Self::Input) -> usize {
+        let path = simulate_path(map, *guard);
+        path.len()
+    }
+
+    fn part2((map, guard): &Self::Input) -> usize {
+        loop_obstructions(map, *guard).len()
+    }
 }
 
 #[cfg(test)]
@@ -107,13 +103,67 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day06::part1(&Day06::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 41);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day06::part2(&Day06::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 6);
     }
+
+    /// Walks the map tile by tile from the start, as a reference for the jump table
+    fn has_loop(map: &Map, obstruction: Point, initial_position: Point) -> bool {
+        let mut guard = initial_position;
+        let mut dir = Direction::Up;
+        let mut visited = HashSet::new();
+        visited.insert((initial_position, dir));
+        while let Some(p) = map.step(guard, dir) {
+            if map[p] == '#' || p == obstruction {
+                dir = dir.turn_right();
+            } else {
+                if !visited.insert((p, dir)) {
+                    return true;
+                }
+                guard = p;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn test_jump_table_matches_walk() {
+        let (map, guard) = Day06::parse(INPUT).expect("Expecting successful parsing");
+        let jumps = JumpTable::new(&map);
+        for (p, dir, obstruction) in obstruction_candidates(&map, guard) {
+            assert_eq!(jumps.has_loop(p, dir, obstruction), has_loop(&map, obstruction, guard), "Obstruction at {obstruction:?}");
+        }
+    }
+
+    #[test]
+    fn test_render_path() {
+        let (map, guard) = Day06::parse(INPUT).expect("Expecting successful parsing");
+        let obstructions = loop_obstructions(&map, guard);
+        let drawing = render(&map, &walk(&map, guard), &obstructions);
+        assert_eq!(drawing.render(|_, &c| c), "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O^-+-+.
+.+----OO#.
+#O-O--+|..
+......#O..
+");
+        assert_eq!(obstructions.len(), 6);
+    }
+
+    #[test]
+    fn test_missing_guard() {
+        let result = Day06::parse("..#\n...\n");
+        assert_eq!(result.err(), Some(ParseError::new(3, 1, "", "guard '^'")));
+    }
 }
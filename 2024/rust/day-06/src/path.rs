@@ -0,0 +1,67 @@
+use grid::{Direction, Grid, Point};
+
+use crate::Map;
+
+/// Tile the guard stands on and the direction it faces
+pub type Step = (Point, Direction);
+
+/// Every step of the guard until leaving the map, turning in place counting as a step
+pub fn walk(map: &Map, initial_position: Point) -> Vec<Step> {
+    let mut steps = vec![(initial_position, Direction::Up)];
+    let (mut guard, mut dir) = steps[0];
+    while let Some(p) = map.step(guard, dir) {
+        if map[p] == '#' {
+            dir = dir.turn_right();
+        } else {
+            guard = p;
+        }
+        steps.push((guard, dir));
+    }
+    steps
+}
+
+/// Map with the path drawn as `|` and `-`, `+` where it turns or crosses itself, and
+/// `O` on the obstructions that would trap the guard in a loop
+pub fn render(map: &Map, steps: &[Step], obstructions: &[Point]) -> Grid<char> {
+    let mut drawing = map.clone();
+    for &(p, dir) in steps {
+        let line = if dir.is_vertical() { '|' } else { '-' };
+        drawing[p] = match drawing[p] {
+            '.' => line,
+            c if c == line || c == '^' => c,
+            _ => '+',
+        };
+    }
+    for &p in obstructions {
+        drawing[p] = 'O';
+    }
+    drawing
+}
+
+/// RGB color of a rendered tile
+pub fn color(tile: char) -> [u8; 3] {
+    match tile {
+        '#' => [88, 88, 88],
+        '|' | '-' | '+' => [38, 139, 210],
+        '^' => [133, 153, 0],
+        'O' => [220, 50, 47],
+        _ => [0, 0, 0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_steps() {
+        let map = Grid::parse(".#.\n...\n.^.\n", "map tile", Some).expect("Expecting successful parsing");
+        let steps = walk(&map, Point::new(1, 2));
+        assert_eq!(steps, vec![
+            (Point::new(1, 2), Direction::Up),
+            (Point::new(1, 1), Direction::Up),
+            (Point::new(1, 1), Direction::Right),
+            (Point::new(2, 1), Direction::Right),
+        ]);
+    }
+}
@@ -1,8 +1,9 @@
-use day_07::prelude::{parse_input, part1, part2};
+use aoc_core::Solution;
+use day_07::prelude::Day07;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    let input = parse_input(content);
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
\ No newline at end of file
+    let input = Day07::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day07::part1(&input));
+    println!("Part 2: {}", Day07::part2(&input));
+}
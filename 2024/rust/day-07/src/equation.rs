@@ -1,4 +1,28 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::operator::Operator;
+
+/// Direction in which the operators are searched
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Search {
+    /// Applies the operators left to right, breadth first
+    #[default]
+    Forward,
+    /// Undoes the operators right to left from the test value, depth first
+    Backward,
+}
+
+impl FromStr for Search {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "forward" => Ok(Search::Forward),
+            "backward" => Ok(Search::Backward),
+            _ => Err("expected forward or backward".to_string()),
+        }
+    }
+}
 
 pub struct Equation {
     pub test_value: usize,
@@ -10,24 +34,192 @@ impl Equation {
         Self { test_value, values }
     }
 
-    pub fn has_solution(&self, concat_op: bool) -> bool {
+    pub fn has_solution(&self, ops: &[Operator], search: Search) -> bool {
+        match search {
+            Search::Forward => self.solution(ops).is_some(),
+            Search::Backward => self.solution_backward(ops).is_some(),
+        }
+    }
+
+    /// Operators placed between the values to reach the test value, found breadth first
+    pub fn solution(&self, ops: &[Operator]) -> Option<Vec<Operator>> {
+        let prune = ops.iter().all(|op| op.is_growing()) && !self.values.contains(&0);
+        // Operator chosen at each explored step, along with the step it came from
+        let mut steps: Vec<(Operator, usize)> = Vec::new();
         let mut pending = VecDeque::new();
-        pending.push_back((1, self.values[0]));
-        while !pending.is_empty() {
-            let (i, total_so_far) = pending.pop_front().expect("Expected item from queue");
+        pending.push_back((1, self.values[0], None));
+        while let Some((i, total_so_far, step)) = pending.pop_front() {
             if i == self.values.len() {
                 if total_so_far == self.test_value {
-                    return true
+                    return Some(Self::sequence(&steps, step));
                 }
-            } else if total_so_far <= self.test_value {
+            } else if !prune || total_so_far <= self.test_value {
                 let v = self.values[i];
-                pending.push_back((i + 1, total_so_far * v));
-                pending.push_back((i + 1, total_so_far + v));
-                if concat_op {
-                    pending.push_back((i + 1, format!("{}{}", total_so_far, v).parse::<usize>().expect("Expected a number to parse")));
+                for &op in ops {
+                    if let Some(total) = op.apply(total_so_far, v) {
+                        steps.push((op, step.unwrap_or(usize::MAX)));
+                        pending.push_back((i + 1, total, Some(steps.len() - 1)));
+                    }
                 }
             }
         }
-        false
+        None
+    }
+
+    /// Operators placed between the values to reach the test value, found by undoing them
+    /// from the last value, so every branch not leading back to the first value is cut early
+    pub fn solution_backward(&self, ops: &[Operator]) -> Option<Vec<Operator>> {
+        undo(&self.values, self.test_value, ops)
+    }
+
+    fn sequence(steps: &[(Operator, usize)], last: Option<usize>) -> Vec<Operator> {
+        let mut sequence = Vec::new();
+        let mut step = last;
+        while let Some(&(op, previous)) = step.and_then(|s| steps.get(s)) {
+            sequence.push(op);
+            step = Some(previous);
+        }
+        sequence.reverse();
+        sequence
+    }
+
+    /// Equation written with `ops` between its values, e.g. `292: 11 + 6 * 16 + 20`
+    pub fn render(&self, ops: &[Operator]) -> String {
+        let mut text = format!("{}: {}", self.test_value, self.values[0]);
+        for (op, v) in ops.iter().zip(&self.values[1..]) {
+            text.push_str(&format!(" {op} {v}"));
+        }
+        text
+    }
+}
+
+/// Operators placed between `values` to reach `result`
+fn undo(values: &[usize], result: usize, ops: &[Operator]) -> Option<Vec<Operator>> {
+    match values {
+        [] => None,
+        [first] => (*first == result).then(Vec::new),
+        [rest @ .., last] => ops
+            .iter()
+            .find_map(|&op| {
+                let mut sequence = match op.undo(result, *last) {
+                    Some(total) => undo(rest, total, ops)?,
+                    None if op.absorbs(result, *last) => any_sequence(rest[0], &rest[1..], ops)?,
+                    None => return None,
+                };
+                sequence.push(op);
+                Some(sequence)
+            }),
+    }
+}
+
+/// Operators placed between `total` and `values` that all apply, whatever total they reach
+fn any_sequence(total: usize, values: &[usize], ops: &[Operator]) -> Option<Vec<Operator>> {
+    match values {
+        [] => Some(Vec::new()),
+        [next, rest @ ..] => ops
+            .iter()
+            .find_map(|&op| {
+                let mut sequence = any_sequence(op.apply(total, *next)?, rest, ops)?;
+                sequence.insert(0, op);
+                Some(sequence)
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const OPERATORS: [Operator; 7] = [
+        Operator::Add,
+        Operator::Mul,
+        Operator::Sub,
+        Operator::Div,
+        Operator::Xor,
+        Operator::Concat(10),
+        Operator::Concat(2),
+    ];
+
+    fn evaluate(values: &[usize], ops: &[Operator]) -> Option<usize> {
+        ops
+            .iter()
+            .zip(&values[1..])
+            .try_fold(values[0], |total, (op, &v)| op.apply(total, v))
+    }
+
+    #[test]
+    fn operator_sequence() {
+        let equation = Equation::new(292, vec![11, 6, 16, 20]);
+        let ops = equation.solution(Operator::PART1).expect("Expecting a solution");
+        assert_eq!(ops, vec![Operator::Add, Operator::Mul, Operator::Add]);
+        assert_eq!(equation.render(&ops), "292: 11 + 6 * 16 + 20");
+    }
+
+    #[test]
+    fn concat_sequence() {
+        let equation = Equation::new(7290, vec![6, 8, 6, 15]);
+        assert_eq!(equation.solution(Operator::PART1), None);
+        let ops = equation.solution(Operator::PART2).expect("Expecting a solution");
+        assert_eq!(equation.render(&ops), "7290: 6 * 8 || 6 * 15");
+    }
+
+    #[test]
+    fn backward_sequence() {
+        let equation = Equation::new(7290, vec![6, 8, 6, 15]);
+        assert_eq!(equation.solution_backward(Operator::PART1), None);
+        let ops = equation.solution_backward(Operator::PART2).expect("Expecting a solution");
+        assert_eq!(equation.render(&ops), "7290: 6 * 8 || 6 * 15");
+        assert!(equation.has_solution(Operator::PART2, Search::Backward));
+    }
+
+    #[test]
+    fn forward_through_zero() {
+        let equation = Equation::new(0, vec![5, 3, 0]);
+        assert!(equation.has_solution(Operator::PART1, Search::Forward));
+    }
+
+    #[test]
+    fn backward_through_zero() {
+        let equation = Equation::new(0, vec![5, 3, 0]);
+        let ops = equation.solution_backward(Operator::PART1).expect("Expecting a solution");
+        assert_eq!(equation.render(&ops), "0: 5 * 3 * 0");
+        let equation = Equation::new(2, vec![5, 0, 2]);
+        let ops = equation.solution_backward(Operator::PART1).expect("Expecting a solution");
+        assert_eq!(equation.render(&ops), "2: 5 * 0 + 2", "Zero reached before the last value");
+    }
+
+    #[test]
+    fn shrinking_operators() {
+        let equation = Equation::new(3, vec![10, 4, 2]);
+        let ops = [Operator::Sub, Operator::Div, Operator::Xor];
+        let solution = equation.solution(&ops).expect("Expecting a solution");
+        assert_eq!(equation.render(&solution), "3: 10 - 4 / 2");
+        let solution = equation.solution_backward(&ops).expect("Expecting a solution");
+        assert_eq!(equation.render(&solution), "3: 10 - 4 / 2");
+    }
+
+    #[test]
+    fn backward_never_divides_by_zero() {
+        let equation = Equation::new(5, vec![0, 0]);
+        assert_eq!(equation.solution(&[Operator::Div]), None);
+        assert_eq!(equation.solution_backward(&[Operator::Div]), None);
+    }
+
+    proptest! {
+        #[test]
+        fn backward_matches_forward(
+            ops in prop::sample::subsequence(OPERATORS.to_vec(), 1..=OPERATORS.len()),
+            values in prop::collection::vec(0usize..12, 1..6),
+            test_value in 0usize..200,
+        ) {
+            let equation = Equation::new(test_value, values);
+            let backward = equation.solution_backward(&ops);
+            prop_assert_eq!(backward.is_some(), equation.solution(&ops).is_some());
+            if let Some(backward) = backward {
+                prop_assert_eq!(evaluate(&equation.values, &backward), Some(test_value));
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,12 @@
 mod equation;
+mod operator;
 mod parser;
 mod solver;
 
 pub mod prelude {
-    pub use crate::parser::parse_input;
-    pub use crate::solver::{part1, part2};
+    pub use crate::{
+        equation::{Equation, Search},
+        operator::Operator,
+        solver::{Calibration, Day07},
+    };
 }
\ No newline at end of file
@@ -0,0 +1,145 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Operator combining the total so far with the next value, always evaluated left to right
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Add,
+    Mul,
+    /// Subtraction, never going below zero
+    Sub,
+    /// Exact division, never leaving a remainder
+    Div,
+    Xor,
+    /// Digits of the value appended to the total, written in the given base, never applying
+    /// in a base below 2
+    Concat(usize),
+}
+
+/// Smallest power of `base` above `value`, moving the total past the digits of `value`,
+/// `None` in a base below 2 where the powers never grow
+fn shift(base: usize, value: usize) -> Option<usize> {
+    if base < 2 {
+        return None;
+    }
+    let mut shift = base;
+    while shift <= value {
+        shift = shift.checked_mul(base)?;
+    }
+    Some(shift)
+}
+
+impl Operator {
+    pub const PART1: &'static [Operator] = &[Operator::Mul, Operator::Add];
+
+    pub const PART2: &'static [Operator] = &[Operator::Mul, Operator::Add, Operator::Concat(10)];
+
+    /// Total after applying the operator, `None` when the result is not a natural number
+    pub fn apply(self, total: usize, value: usize) -> Option<usize> {
+        match self {
+            Operator::Add => total.checked_add(value),
+            Operator::Mul => total.checked_mul(value),
+            Operator::Sub => total.checked_sub(value),
+            Operator::Div => (value != 0 && total.is_multiple_of(value)).then(|| total / value),
+            Operator::Xor => Some(total ^ value),
+            Operator::Concat(base) => total.checked_mul(shift(base, value)?)?.checked_add(value),
+        }
+    }
+
+    /// Total before applying the operator, given the total after it, `None` when no natural
+    /// number leads to `result` or when every one does, as told by `absorbs`
+    pub fn undo(self, result: usize, value: usize) -> Option<usize> {
+        match self {
+            Operator::Add => result.checked_sub(value),
+            Operator::Mul => match value {
+                0 => None,
+                v => result.is_multiple_of(v).then(|| result / v),
+            },
+            Operator::Sub => result.checked_add(value),
+            Operator::Div => match value {
+                0 => None,
+                v => result.checked_mul(v),
+            },
+            Operator::Xor => Some(result ^ value),
+            Operator::Concat(base) => {
+                let shift = shift(base, value)?;
+                (result % shift == value).then(|| result / shift)
+            },
+        }
+    }
+
+    /// Whether every total leads to `result`, as multiplying by zero does
+    pub fn absorbs(self, result: usize, value: usize) -> bool {
+        self == Operator::Mul && value == 0 && result == 0
+    }
+
+    /// Whether the total never shrinks, short of multiplying by zero, so totals above the test
+    /// value can be dropped
+    pub fn is_growing(self) -> bool {
+        matches!(self, Operator::Add | Operator::Mul | Operator::Concat(_))
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Mul => write!(f, "*"),
+            Operator::Sub => write!(f, "-"),
+            Operator::Div => write!(f, "/"),
+            Operator::Xor => write!(f, "^"),
+            Operator::Concat(10) => write!(f, "||"),
+            Operator::Concat(base) => write!(f, "||{base}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_operators() {
+        assert_eq!(Operator::Sub.apply(10, 4), Some(6));
+        assert_eq!(Operator::Sub.apply(4, 10), None);
+        assert_eq!(Operator::Div.apply(12, 4), Some(3));
+        assert_eq!(Operator::Div.apply(12, 5), None);
+        assert_eq!(Operator::Div.apply(12, 0), None);
+        assert_eq!(Operator::Xor.apply(0b1100, 0b1010), Some(0b0110));
+    }
+
+    #[test]
+    fn concat_in_base() {
+        assert_eq!(Operator::Concat(10).apply(12, 345), Some(12345));
+        assert_eq!(Operator::Concat(10).apply(12, 0), Some(120));
+        assert_eq!(Operator::Concat(10).apply(12, 10), Some(1210));
+        assert_eq!(Operator::Concat(2).apply(0b11, 0b101), Some(0b11101));
+        assert_eq!(Operator::Concat(10).apply(usize::MAX, 1), None);
+    }
+
+    #[test]
+    fn concat_below_base_two() {
+        for base in [0, 1] {
+            assert_eq!(Operator::Concat(base).apply(12, 345), None);
+            assert_eq!(Operator::Concat(base).undo(12345, 345), None);
+        }
+    }
+
+    #[test]
+    fn undo_operators() {
+        assert_eq!(Operator::Add.undo(10, 4), Some(6));
+        assert_eq!(Operator::Add.undo(4, 10), None);
+        assert_eq!(Operator::Mul.undo(12, 4), Some(3));
+        assert_eq!(Operator::Mul.undo(12, 5), None);
+        assert_eq!(Operator::Mul.undo(0, 0), None);
+        assert_eq!(Operator::Div.undo(3, 4), Some(12));
+        assert_eq!(Operator::Div.undo(5, 0), None);
+        assert!(Operator::Mul.absorbs(0, 0));
+        assert!(!Operator::Mul.absorbs(12, 0));
+        assert_eq!(Operator::Concat(10).undo(12345, 345), Some(12));
+        assert_eq!(Operator::Concat(10).undo(12345, 45), Some(123));
+        assert_eq!(Operator::Concat(10).undo(12345, 346), None);
+        assert_eq!(Operator::Concat(10).undo(120, 0), Some(12));
+        assert_eq!(Operator::Sub.undo(6, 4), Some(10));
+        assert_eq!(Operator::Div.undo(3, 4), Some(12));
+    }
+}
@@ -1,22 +1,33 @@
+use aoc_core::{parser::{finish, tag, IResult}, ParseError};
 use nom::{
-    bytes::complete::tag,
-    character::complete::{char, line_ending, usize},
-    combinator::eof,
-    multi::{many1, separated_list1},
-    sequence::{separated_pair, terminated},
-    IResult,
+    character::complete::{char, usize},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::separated_pair,
     Parser
 };
 
 use crate::equation::Equation;
 
-fn parse_equation(input: &str) -> IResult<&str, Equation> {
+fn parse_equation(input: &str) -> IResult<'_, Equation> {
     let (input, (test_value, values)) = separated_pair(usize, tag(": "), separated_list1(char(' '), usize)).parse(input)?;
     Ok((input, Equation::new(test_value, values)))
 }
 
-pub fn parse_input(input: &str) -> Vec<Equation> {
-    let pequation_nl = terminated(parse_equation, line_ending);
-    let (_input, equations) = terminated(many1(pequation_nl), eof).parse(input).expect("Expected valid equations input");
-    equations
-}
\ No newline at end of file
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input
+        .lines()
+        .map(|line| finish(input, all_consuming(parse_equation).parse(line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_colon() {
+        let result = parse_input("190: 10 19\n3267 81 40 27\n");
+        assert_eq!(result.err(), Some(ParseError::new(2, 5, " 81 40 27", "': '")));
+    }
+}
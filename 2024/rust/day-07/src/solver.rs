@@ -1,25 +1,63 @@
-use crate::equation::Equation;
-
-pub fn part1(input: &[Equation]) -> usize {
-    input
-        .iter()
-        .filter(|e| e.has_solution(false))
-        .map(|e| e.test_value)
-        .sum()
+use aoc_core::{Options, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{equation::{Equation, Search}, operator::Operator, parser};
+
+pub struct Day07;
+
+/// Equations of the puzzle along with the search solving them
+pub struct Calibration {
+    pub equations: Vec<Equation>,
+    pub search: Search,
 }
 
-pub fn part2(input: &[Equation]) -> usize {
-    input
-        .iter()
-        .filter(|e| e.has_solution(true))
-        .map(|e| e.test_value)
-        .sum()
+impl Calibration {
+    /// Sum of the test values of the equations solved with `ops`
+    pub fn total(&self, ops: &[Operator]) -> usize {
+        #[cfg(feature = "parallel")]
+        let equations = self.equations.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let equations = self.equations.iter();
+        equations
+            .filter(|e| e.has_solution(ops, self.search))
+            .map(|e| e.test_value)
+            .sum()
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Calibration;
+    type Answer = usize;
+
+    const OPTIONS: &'static [&'static str] = &["search"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let equations = parser::parse_input(input)?;
+        Ok(Calibration { equations, search: Search::default() })
+    }
+
+    fn configure(mut calibration: Self::Input, options: &Options) -> Result<Self::Input, String> {
+        if let Some(search) = options.get("search")? {
+            calibration.search = search;
+        }
+        Ok(calibration)
+    }
+
+    fn part1(calibration: &Self::Input) -> usize {
+        calibration.total(Operator::PART1)
+    }
+
+    fn part2(calibration: &Self::Input) -> usize {
+        calibration.total(Operator::PART2)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser;
 
     const INPUT: &str = "\
 190: 10 19
@@ -35,13 +73,21 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(&parser::parse_input(INPUT));
+        let result = Day07::part1(&Day07::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 3749);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(&parser::parse_input(INPUT));
+        let result = Day07::part2(&Day07::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 11387);
     }
+
+    #[test]
+    fn test_backward_search() {
+        let mut calibration = Day07::parse(INPUT).expect("Expecting successful parsing");
+        calibration.search = Search::Backward;
+        assert_eq!(Day07::part1(&calibration), 3749);
+        assert_eq!(Day07::part2(&calibration), 11387);
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
-use day_08::part1;
+use aoc_core::Solution;
+use day_08::Day08;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
+    let input = Day08::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day08::part1(&input));
 }
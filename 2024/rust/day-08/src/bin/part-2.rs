@@ -1,6 +1,8 @@
-use day_08::part2;
+use aoc_core::Solution;
+use day_08::Day08;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
+    let input = Day08::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day08::part2(&input));
 }
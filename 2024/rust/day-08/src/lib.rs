@@ -3,84 +3,48 @@ use std::{
     iter::successors
 };
 
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
 use itertools::Itertools;
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-struct Antinode {
-    x: isize,
-    y: isize,
-}
-
-struct Antenna {
-    x: usize,
-    y: usize,
-}
+pub struct Day08;
 
-struct Map {
-    antennas: HashMap<char, Vec<Antenna>>,
-    height: usize,
-    width: usize,
+pub struct Map {
+    antennas: HashMap<char, Vec<Point>>,
+    grid: Grid<char>,
 }
 
-struct _AntinodeRay {
-    map: Map,
-    source: Antenna,
-    target: Option<Antenna>,
-}
-
-fn parse_input(input: &str) -> Map {
-    let lines: Vec<&str> = input.lines().collect();
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let grid = Grid::parse(input, "map tile", Some)?;
     let mut antennas = HashMap::new();
-    for (y, line) in lines.iter().enumerate() {
-        for (x, ch) in line.char_indices() {
-            if ch != '.' {
-                antennas
-                    .entry(ch)
-                    .or_insert(Vec::new())
-                    .push(Antenna {x, y});
-            }
+    for (p, &ch) in grid.iter() {
+        if ch != '.' {
+            antennas
+                .entry(ch)
+                .or_insert(Vec::new())
+                .push(p);
         }
     }
-    Map {
-        antennas,
-        height: lines.len(),
-        width: lines[0].len(),
-    }
+    Ok(Map { antennas, grid })
 }
 
-impl Antenna {
-    fn antinode(&self, other: &Self) -> Antinode {
-        let dx = other.x as isize - self.x as isize;
-        let dy = other.y as isize - self.y as isize;
-        Antinode {
-            x: other.x as isize + dx,
-            y: other.y as isize + dy,
-        }
-    }
-
-    fn antinodes(&self, other: &Self, map: &Map) -> Vec<Antinode> {
-        let dx = other.x as isize - self.x as isize;
-        let dy = other.y as isize - self.y as isize;
-        let first = Antinode {
-            x: self.x as isize,
-            y: self.y as isize,
-        };
-        successors(Some(first), |&antinode| {
-            let a = Antinode {
-                x: antinode.x + dx,
-                y: antinode.y + dy,
-            };
-            if map.in_range(&a) { Some(a) } else { None }
-        }).collect::<Vec<Antinode>>()
-    }
+fn delta(a: Point, b: Point) -> (isize, isize) {
+    (b.x as isize - a.x as isize, b.y as isize - a.y as isize)
 }
 
 impl Map {
-    fn in_range(&self, a: &Antinode) -> bool {
-        0 <= a.x && a.x < self.width as isize && 0 <= a.y && a.y < self.height as isize
+    fn antinode(&self, a: Point, b: Point) -> Option<Point> {
+        let (dx, dy) = delta(a, b);
+        self.grid.offset(b, dx, dy)
     }
 
-    fn antinodes(&self, antennas: &Vec<Antenna>, antinode_projection: bool) -> Vec<Antinode> {
+    fn antinodes_projection(&self, a: Point, b: Point) -> Vec<Point> {
+        let (dx, dy) = delta(a, b);
+        successors(Some(a), |&antinode| self.grid.offset(antinode, dx, dy))
+            .collect()
+    }
+
+    fn antinodes(&self, antennas: &[Point], antinode_projection: bool) -> Vec<Point> {
         antennas
             .iter()
             .enumerate()
@@ -89,34 +53,42 @@ impl Map {
                     .iter()
                     .enumerate()
                     .filter(move |(j, _b)| i != *j)
-                    .map(move |(_j, b)| (a, b)))
+                    .map(move |(_j, b)| (*a, *b)))
             .flat_map(|(a, b)|
                 if antinode_projection {
-                    a.antinodes(b, self)
+                    self.antinodes_projection(a, b)
                 } else {
-                    vec![a.antinode(b)]
+                    self.antinode(a, b).into_iter().collect()
                 })
-            .filter(|p| self.in_range(p))
             .collect()
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let map = parse_input(input);
-    map.antennas
-        .values()
-        .flat_map(|antennas| map.antinodes(antennas, false))
-        .unique()
-        .count()
-}
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Answer = usize;
 
-pub fn part2(input: &str) -> usize {
-    let map = parse_input(input);
-    map.antennas
-        .values()
-        .flat_map(|antennas| map.antinodes(antennas, true))
-        .unique()
-        .count()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        map.antennas
+            .values()
+            .flat_map(|antennas| map.antinodes(antennas, false))
+            .unique()
+            .count()
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        map.antennas
+            .values()
+            .flat_map(|antennas| map.antinodes(antennas, true))
+            .unique()
+            .count()
+    }
 }
 
 #[cfg(test)]
@@ -139,13 +111,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day08::part1(&Day08::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 14);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day08::part2(&Day08::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 34);
     }
 }
@@ -1,7 +1,32 @@
+use aoc_core::{ParseError, Solution};
+
 mod part1;
 mod part2;
 
-pub use {
-    part1::*,
-    part2::*,
-};
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim_end()
+            .char_indices()
+            .map(|(i, c)| c
+                .to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::at(input, &input[i..], "digit")))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2::part2(input)
+    }
+}
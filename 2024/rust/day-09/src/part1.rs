@@ -17,12 +17,12 @@ struct DiskCompactingIterator<'a> {
     disk: &'a Disk,
 }
 
-fn parse_input(input: &str) -> Disk {
+fn parse_input(disk_map: &[usize]) -> Disk {
     let blocks =
-        input
-            .char_indices()
-            .flat_map(|(i, c)| {
-                let d = c.to_digit(10).expect("Expected a digit") as usize;
+        disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &d)| {
                 if i % 2 == 0 {
                     let file_id = i / 2;
                     vec![Block::File(file_id); d]
@@ -67,7 +67,7 @@ impl<'a> Iterator for DiskCompactingIterator<'a> {
 }
 
 impl Disk {
-    fn compact(&self) -> DiskCompactingIterator {
+    fn compact(&self) -> DiskCompactingIterator<'_> {
         DiskCompactingIterator {
             left: 0,
             right: self.blocks.len() as isize - 1,
@@ -85,8 +85,8 @@ impl<'a> DiskCompactingIterator<'a> {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let disk = parse_input(input);
+pub fn part1(disk_map: &[usize]) -> usize {
+    let disk = parse_input(disk_map);
     disk
         .compact()
         .checksum()
@@ -94,13 +94,15 @@ pub fn part1(input: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day09;
 
     const INPUT: &str = "2333133121414131402";
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day09::part1(&Day09::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 1928);
     }
 }
\ No newline at end of file
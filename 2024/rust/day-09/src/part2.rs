@@ -18,12 +18,12 @@ struct Disk {
     items: Vec<DiskItem>
 }
 
-fn parse_input(input: &str) -> Disk {
+fn parse_input(disk_map: &[usize]) -> Disk {
     let items: Vec<DiskItem> =
-        input
-            .char_indices()
-            .map(|(i, c)| {
-                let d = c.to_digit(10).expect("Expected a digit") as usize;
+        disk_map
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| {
                 if i % 2 == 0 {
                     let file_id = i / 2;
                     DiskItem::File(File { id: file_id, length: d })
@@ -38,27 +38,21 @@ fn parse_input(input: &str) -> Disk {
 fn compact(disk: &mut Disk) {
     let mut right = disk.items.len() - 1;
     while right > 0 {
-        match disk.items[right] {
-            DiskItem::File(f) => {
-                disk.items
-                    .iter()
-                    .enumerate()
-                    .take_while(|&(i, _item)| i < right)
-                    .position(|(_i, item)| match item {
-                        DiskItem::FreeSpace(fs) if fs.length >= f.length => true,
-                        _ => false
-                    })
-                    .inspect(|&i| {
-                        if let DiskItem::FreeSpace(mut available_space) = disk.items[i] {
-                            available_space.length -= f.length;
-                            disk.items[i] = DiskItem::FreeSpace(available_space);
-                            let file_item = disk.items.remove(right);
-                            disk.items.insert(i, file_item);
-                            disk.items.insert(right, DiskItem::FreeSpace(FreeSpace { length: f.length }));
-                        }
-                    });
-            },
-            _ => (),
+        if let DiskItem::File(f) = disk.items[right] {
+            disk.items
+                .iter()
+                .enumerate()
+                .take_while(|&(i, _item)| i < right)
+                .position(|(_i, item)| matches!(item, DiskItem::FreeSpace(fs) if fs.length >= f.length))
+                .inspect(|&i| {
+                    if let DiskItem::FreeSpace(mut available_space) = disk.items[i] {
+                        available_space.length -= f.length;
+                        disk.items[i] = DiskItem::FreeSpace(available_space);
+                        let file_item = disk.items.remove(right);
+                        disk.items.insert(i, file_item);
+                        disk.items.insert(right, DiskItem::FreeSpace(FreeSpace { length: f.length }));
+                    }
+                });
         }
         right -= 1;
     }
@@ -76,20 +70,23 @@ fn checksum(disk: Disk) -> usize {
         .sum()
 }
 
-pub fn part2(input: &str) -> usize {
-    let mut disk = parse_input(input);
+pub fn part2(disk_map: &[usize]) -> usize {
+    let mut disk = parse_input(disk_map);
     compact(&mut disk);
     checksum(disk)
 }
 
+#[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day09;
 
     const INPUT: &str = "2333133121414131402";
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day09::part2(&Day09::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 2858);
     }
 }
\ No newline at end of file
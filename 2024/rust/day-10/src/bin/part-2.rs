@@ -1,6 +1,8 @@
-use day_10::part2;
+use aoc_core::Solution;
+use day_10::Day10;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
-}
\ No newline at end of file
+    let input = Day10::parse(content).expect("Expecting valid input");
+    println!("Part 2: {}", Day10::part2(&input));
+}
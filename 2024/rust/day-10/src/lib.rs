@@ -1,124 +1,92 @@
 use std::collections::{HashSet, VecDeque};
 
-const DIRS: [(isize, isize); 4] = [
-    (-1,  0), // Up
-    ( 0, -1), // Left
-    ( 1,  0), // Down
-    ( 0,  1), // Right
-];
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
 
-struct Map(Vec<Vec<usize>>);
+pub struct Day10;
 
-impl Map {
-    fn value(&self, row: usize, col: usize) -> usize {
-        self.0[row][col]
-    }
+pub struct Map(Grid<usize>);
 
-    fn height(&self) -> usize {
-        self.0.len()
+impl Map {
+    fn uphill(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let next_h = self.0[p] + 1;
+        self.0
+            .neighbors4(p)
+            .filter(move |&q| self.0[q] == next_h)
     }
 
-    fn width(&self) -> usize {
-        self.0[0].len()
+    fn trailheads(&self) -> impl Iterator<Item = Point> + '_ {
+        self.0
+            .points()
+            .filter(|&p| self.0[p] == 0)
     }
 
-    fn score(&self, row: usize, col: usize) -> usize {
+    fn score(&self, trailhead: Point) -> usize {
         let mut score = 0;
         let mut visited = HashSet::new();
         let mut pending = VecDeque::new();
-        pending.push_back((row, col));
-        while let Some(coord) = pending.pop_front() {
-            if visited.contains(&coord) {
+        pending.push_back(trailhead);
+        while let Some(p) = pending.pop_front() {
+            if visited.contains(&p) {
                 continue;
             }
-            let (y, x) = coord;
-            let h = self.value(y, x);
-            if h == 9 {
+            if self.0[p] == 9 {
                 score += 1;
             } else {
-                let next_h = h + 1;
-                for (dy, dx) in DIRS {
-                    let next_y = y as isize + dy;
-                    let next_x = x as isize + dx;
-                    if next_y >= 0 && next_x >= 0 {
-                        let next_y = next_y as usize;
-                        let next_x = next_x as usize;
-                        if next_y < self.height() && next_x < self.width() && self.value(next_y, next_x) == next_h {
-                            pending.push_back((next_y, next_x));
-                        }
-                    }
-                }
+                pending.extend(self.uphill(p));
             }
-            visited.insert(coord);
+            visited.insert(p);
         }
         score
     }
     
-    fn rating(&self, row: usize, col: usize) -> usize {
-        let mut paths: Vec<Vec<usize>> = (0..self.height())
-            .map(|_| vec![0; self.width()])
-            .collect();
+    fn rating(&self, trailhead: Point) -> usize {
+        let mut paths = Grid::new(self.0.width(), self.0.height(), 0);
         let mut peaks = HashSet::new();
         let mut pending = VecDeque::new();
-        pending.push_back((row, col));
-        while let Some(coord) = pending.pop_front() {
-            let (y, x) = coord;
-            let h = self.value(y, x);
-            paths[y][x] += 1;
-            if h == 9 {
-                peaks.insert(coord);
+        pending.push_back(trailhead);
+        while let Some(p) = pending.pop_front() {
+            paths[p] += 1;
+            if self.0[p] == 9 {
+                peaks.insert(p);
             } else {
-                let next_h = h + 1;
-                for (dy, dx) in DIRS {
-                    let next_y = y as isize + dy;
-                    let next_x = x as isize + dx;
-                    if next_y >= 0 && next_x >= 0 {
-                        let next_y = next_y as usize;
-                        let next_x = next_x as usize;
-                        if next_y < self.height() && next_x < self.width() && self.value(next_y, next_x) == next_h {
-                            pending.push_back((next_y, next_x));
-                        }
-                    }
-                }
+                pending.extend(self.uphill(p));
             }
         }
         let rating = peaks
             .iter()
-            .map(|&(y, x)| paths[y][x])
+            .map(|&p| paths[p])
             .sum();
         rating
     }
 
-    fn parse(input: &str) -> Self {
-        let values = input
-            .lines()
-            .map(|line| line.as_bytes()
-                .iter()
-                .map(|&b| (b - b'0') as usize)
-                .collect::<Vec<usize>>())
-            .collect();
-        Self(values)
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let values = Grid::parse(input, "height digit", |c| c.to_digit(10).map(|d| d as usize))?;
+        Ok(Self(values))
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let map = Map::parse(input);
-    (0..map.height())
-        .map(|row| (0..map.width())
-            .filter(|&col| map.value(row, col) == 0)
-            .map(|col| map.score(row, col))
-            .sum::<usize>())
-        .sum()
-}
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Answer = usize;
 
-pub fn part2(input: &str) -> usize {
-    let map = Map::parse(input);
-    (0..map.height())
-        .map(|row| (0..map.width())
-            .filter(|&col| map.value(row, col) == 0)
-            .map(|col| map.rating(row, col))
-            .sum::<usize>())
-        .sum()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        map.trailheads()
+            .map(|p| map.score(p))
+            .sum()
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        map.trailheads()
+            .map(|p| map.rating(p))
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -137,13 +105,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day10::part1(&Day10::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 36);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day10::part2(&Day10::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 81);
     }
 }
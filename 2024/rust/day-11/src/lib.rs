@@ -1,5 +1,9 @@
 use std::{cmp::min, collections::HashMap};
 
+use aoc_core::{ParseError, Solution};
+
+pub struct Day11;
+
 fn subtree_index() -> HashMap<usize, Vec<Vec<usize>>> {
     let sub_trees = vec![
         (0, vec![
@@ -78,7 +82,7 @@ fn blink(v: usize) -> Vec<usize> {
         vec![1]
     } else {
         let s = v.to_string();
-        if s.len() % 2 == 0 {
+        if s.len().is_multiple_of(2) {
             vec![
                 s[..s.len() / 2].parse().expect("Expecting digits"),
                 s[s.len() / 2..].parse().expect("Expecting digits")
@@ -89,24 +93,14 @@ fn blink(v: usize) -> Vec<usize> {
     }
 }
 
-fn blink_bfs(input: &Vec<usize>) -> Vec<usize> {
+fn blink_bfs(input: &[usize]) -> Vec<usize> {
     input
         .iter()
         .flat_map(|&v| blink(v))
         .collect()
 }
 
-pub fn part1(input: &str) -> usize {
-    let initial = input
-        .split(' ')
-        .map(|s| s.parse().expect("Expecting digits"))
-        .collect();
-    (0..25)
-        .fold(initial, |stones_so_far, _i| blink_bfs(&stones_so_far))
-        .len()
-}
-
-fn blink_dfs(values: &Vec<usize>, level_so_far: usize, max_level: usize, subtree_index: &HashMap<usize, Vec<Vec<usize>>>, cache: &mut HashMap<(usize, usize), usize>) -> usize {
+fn blink_dfs(values: &[usize], level_so_far: usize, max_level: usize, subtree_index: &HashMap<usize, Vec<Vec<usize>>>, cache: &mut HashMap<(usize, usize), usize>) -> usize {
     if level_so_far == max_level {
         values.len()
     } else {
@@ -123,7 +117,7 @@ fn blink_dfs(values: &Vec<usize>, level_so_far: usize, max_level: usize, subtree
                     } else {
                         (level_so_far + 1, &blink(v))
                     };
-                let result = blink_dfs(&next_values, next_level, max_level, subtree_index, cache);
+                let result = blink_dfs(next_values, next_level, max_level, subtree_index, cache);
                 total += result;
                 cache.insert(key, result);
             }
@@ -132,14 +126,30 @@ fn blink_dfs(values: &Vec<usize>, level_so_far: usize, max_level: usize, subtree
     }
 }
 
-pub fn part2(input: &str) -> usize {
-    let initial = input
-        .split(' ')
-        .map(|s| s.parse().expect("Expecting digits"))
-        .collect();
-    let subtree_index = subtree_index();
-    let mut cache = HashMap::new();
-    blink_dfs(&initial, 0, 75, &subtree_index, &mut cache)
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split_whitespace()
+            .map(|s| s.parse().map_err(|_| ParseError::at(input, s, "stone number")))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        (0..25)
+            .fold(input.clone(), |stones_so_far, _i| blink_bfs(&stones_so_far))
+            .len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        let subtree_index = subtree_index();
+        let mut cache = HashMap::new();
+        blink_dfs(input, 0, 75, &subtree_index, &mut cache)
+    }
 }
 
 #[cfg(test)]
@@ -150,13 +160,13 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day11::part1(&Day11::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 55312);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day11::part2(&Day11::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 65601038650482);
     }
 }
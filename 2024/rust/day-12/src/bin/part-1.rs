@@ -1,6 +1,8 @@
-use day_12::part1;
+use aoc_core::Solution;
+use day_12::Day12;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 1: {}", part1(&content));
-}
\ No newline at end of file
+    let input = Day12::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day12::part1(&input));
+}
@@ -1,136 +1,72 @@
 use std::collections::{HashSet, VecDeque};
 
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point, COMPASS};
+
+pub struct Day12;
+
+type Garden = Grid<char>;
+
 struct Region {
     area: usize,
     perimeter: usize,
     sides: usize,
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-const DIRS: [(isize, isize); 4] = [
-    (-1,  0), // Up
-    ( 0, -1), // Left
-    ( 1,  0), // Down
-    ( 0,  1), // Right
-];
-
-const BORDER: [(isize, isize); 8] = [
-    (-1, -1), // Upper left
-    (-1,  0), // Up
-    (-1,  1), // Upper right
-    ( 0,  1), // Right
-    ( 1,  1), // Lower right
-    ( 1,  0), // Down
-    ( 1, -1), // Lower left
-    ( 0, -1), // Left
-];
-
-impl Point {
-    fn perimeter(neighbors: usize) -> usize {
-        if neighbors > 4 {
-            panic!("Unexpected neighbors {neighbors}")
-        }
-        4 - neighbors
+fn perimeter(neighbors: usize) -> usize {
+    if neighbors > 4 {
+        panic!("Unexpected neighbors {neighbors}")
     }
+    4 - neighbors
+}
 
-    fn corners(border: &[Option<Point>]) -> usize {
-        let mut corners = 0;
-        
-        // when adjacents are different, the opposite does not matter
-        if border[7].is_none() && border[1].is_none() {
-            corners += 1;
-        }
-        if border[1].is_none() && border[3].is_none() {
-            corners += 1;
-        }
-        if border[3].is_none() && border[5].is_none() {
-            corners += 1;
-        }
-        if border[5].is_none() && border[7].is_none() {
-            corners += 1;
-        }
-
-        // when adjacents are equal, the opposite must be different
-        if border[7].is_some() && border[0].is_none() && border[1].is_some() {
-            corners += 1;
-        }
-        if border[1].is_some() && border[2].is_none() && border[3].is_some() {
-            corners += 1;
-        }
-        if border[3].is_some() && border[4].is_none() && border[5].is_some() {
-            corners += 1;
-        }
-        if border[5].is_some() && border[6].is_none() && border[7].is_some() {
-            corners += 1;
-        }
-
-        corners
-    }
+/// `border` holds whether each compass neighbor, clockwise from up, is in the same region
+fn corners(border: &[bool; 8]) -> usize {
+    (0..8)
+        .step_by(2)
+        .filter(|&i| {
+            let adjacent = border[i];
+            let diagonal = border[i + 1];
+            let next_adjacent = border[(i + 2) % 8];
+            // when adjacents are different, the opposite does not matter
+            // when adjacents are equal, the opposite must be different
+            (!adjacent && !next_adjacent) || (adjacent && !diagonal && next_adjacent)
+        })
+        .count()
+}
 
-    fn neighbors(&self, lines: &[&[u8]]) -> Vec<Self> {
-        let plant = lines[self.y][self.x];
-        DIRS
-            .iter()
-            .filter_map(|&(dy, dx)| {
-                let x = self.x as isize + dx;
-                let y = self.y as isize + dy;
-                if y >= 0 && x >= 0 {
-                    let x = x as usize;
-                    let y = y as usize;
-                    if y < lines.len() && x < lines[0].len() && lines[y][x] == plant {
-                        return Some(Point { x, y });
-                    }
-                }
-                None
-            }).collect()
-    }
+fn neighbors(garden: &Garden, p: Point) -> Vec<Point> {
+    let plant = garden[p];
+    garden
+        .neighbors4(p)
+        .filter(|&q| garden[q] == plant)
+        .collect()
+}
 
-    fn border(&self, lines: &[&[u8]]) -> Vec<Option<Point>> {
-        let plant = lines[self.y][self.x];
-        BORDER
-            .iter()
-            .map(|&(dy, dx)| {
-                let x = self.x as isize + dx;
-                let y = self.y as isize + dy;
-                if y < 0 || x < 0 || y >= lines.len() as isize || x >= lines[0].len() as isize {
-                    None
-                } else {
-                    let x = x as usize;
-                    let y = y as usize;
-                    if lines[y][x] != plant {
-                        None
-                    } else {
-                        Some(Point { x, y })
-                    }
-                }
-            })
-            .collect()
-    }
+fn border(garden: &Garden, p: Point) -> [bool; 8] {
+    let plant = garden[p];
+    COMPASS.map(|(dx, dy)| garden
+        .offset(p, dx, dy)
+        .is_some_and(|q| garden[q] == plant))
 }
 
 impl Region {
-    fn new(lines: &[&[u8]], initial_position: Point, visited: &mut HashSet<Point>) -> Region {
+    fn new(garden: &Garden, initial_position: Point, visited: &mut HashSet<Point>) -> Region {
         let mut q = VecDeque::new();
-        let mut perimeter = 0;
+        let mut perimeter_so_far = 0;
         let mut area = 0;
-        let mut corners = 0;
+        let mut corners_so_far = 0;
         q.push_back(initial_position);
         while let Some(p) = q.pop_front() {
             if !visited.insert(p) {
                 continue;
             }
 
-            let neighbors = p.neighbors(lines);
-            perimeter += Point::perimeter(neighbors.len());
+            let neighbors = neighbors(garden, p);
+            perimeter_so_far += perimeter(neighbors.len());
             area += 1;
     
-            let border = p.border(lines);
-            corners += Point::corners(&border);
+            corners_so_far += corners(&border(garden, p));
 
             neighbors
                 .iter()
@@ -138,7 +74,7 @@ impl Region {
                 .for_each(|&n| q.push_back(n));
         }
 
-        Region { area, perimeter, sides: corners }
+        Region { area, perimeter: perimeter_so_far, sides: corners_so_far }
     }
 
     fn price(&self, discount: bool) -> usize {
@@ -147,39 +83,39 @@ impl Region {
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<&[u8]> {
-    input
-        .lines()
-        .map(|line| line.as_bytes())
-        .collect()
-}
-
-pub fn run(lines: &[&[u8]], discount: bool) -> usize {    
+fn run(garden: &Garden, discount: bool) -> usize {    
     let mut visited = HashSet::new();
 
-    (0..lines.len())
-        .flat_map(|y|
-            (0..lines[y].len())
-                .map(move |x| Point { x, y }))
+    garden
+        .points()
         .filter_map(|p|
             if visited.contains(&p) {
                 None
             } else {
-                let r = Region::new(&lines, p, &mut visited);
+                let r = Region::new(garden, p, &mut visited);
                 Some(r)
             })
         .map(|r| r.price(discount))
         .sum()
 }
 
-pub fn part1(input: &str) -> usize {
-    let lines = parse_input(input);
-    run(&lines, false)
-}
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Garden;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "plant", Some)
+    }
+
+    fn part1(garden: &Self::Input) -> usize {
+        run(garden, false)
+    }
 
-pub fn part2(input: &str) -> usize {
-    let lines = parse_input(input);
-    run(&lines, true)
+    fn part2(garden: &Self::Input) -> usize {
+        run(garden, true)
+    }
 }
 
 #[cfg(test)]
@@ -200,13 +136,13 @@ MMMISSJEEE
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day12::part1(&Day12::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 1930);
     }
 
     #[test]
     fn test_part2() {
-        let result = part2(INPUT);
+        let result = Day12::part2(&Day12::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 1206);
     }
 }
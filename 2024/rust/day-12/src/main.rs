@@ -1,8 +1,9 @@
-use day_12::{parse_input, run};
+use aoc_core::Solution;
+use day_12::Day12;
 
 fn main() {
     let content = include_str!("../input.txt");
-    let lines = parse_input(&content);
-    println!("Part 1: {}", run(&lines, false));
-    println!("Part 2: {}", run(&lines, true));
-}
\ No newline at end of file
+    let input = Day12::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day12::part1(&input));
+    println!("Part 2: {}", Day12::part2(&input));
+}
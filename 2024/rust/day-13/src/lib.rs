@@ -1,37 +1,30 @@
+use aoc_core::{ParseError, Solution};
+
+mod machine;
 mod parser;
+mod solver;
 
-/// Solves x and y in the equations ax + by = c and dx + ey = f
-fn solve(a: isize, b: isize, c: isize, d: isize, e: isize, f: isize) -> Option<(isize, isize)> {
-    let y = (a*f - d*c) / (a*e - d*b);
-    let x = (c - b*y) / a;
-    if a*x + b*y == c && d*x + e*y == f {
-        Some((x, y))
-    } else {
-        None
-    }
-}
+pub use machine::{total_tokens, ClawMachine, Coords, Rules};
 
-pub fn run(input: &[(isize, isize, isize, isize, isize, isize)], prize_offset: bool) -> usize {
-    input
-        .iter()
-        .filter_map(|&(a, b, c, d, e, f)|
-            if prize_offset {
-                solve(a, b, c + 10_000_000_000_000, d, e, f + 10_000_000_000_000)
-            } else {
-                solve(a, b, c, d, e, f)
-            })
-        .map(|(a, b)| a as usize * 3 + b as usize)
-        .sum()
-}
+pub struct Day13;
 
-pub fn part1(input: &str) -> usize {
-    let (_, arcades) = parser::parse_input(input).expect("Expecting parsed input");
-    run(&arcades, false)
-}
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+    type Answer = usize;
 
-pub fn part2(input: &str) -> usize {
-    let (_, arcades) = parser::parse_input(input).expect("Expecting parsed input");
-    run(&arcades, true)
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> usize {
+        total_tokens(machines, &Rules::PART1)
+    }
+
+    fn part2(machines: &Self::Input) -> usize {
+        total_tokens(machines, &Rules::PART2)
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +50,7 @@ Prize: X=18641, Y=10279
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day13::part1(&Day13::parse(INPUT).expect("Expecting successful parsing"));
         assert_eq!(result, 480);
     }
 }
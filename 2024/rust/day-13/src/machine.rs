@@ -0,0 +1,88 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::solver;
+
+/// X and Y coordinates, of a claw movement or a prize location
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coords {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Coords {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClawMachine {
+    pub button_a: Coords,
+    pub button_b: Coords,
+    pub prize: Coords,
+}
+
+/// Rules of an arcade variant
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rules {
+    /// Tokens spent on each press of button A
+    pub a_cost: isize,
+    /// Tokens spent on each press of button B
+    pub b_cost: isize,
+    /// Distance added to both coordinates of every prize
+    pub prize_offset: isize,
+    /// Most presses allowed on each button
+    pub press_limit: Option<isize>,
+}
+
+impl Rules {
+    pub const PART1: Rules = Rules { a_cost: 3, b_cost: 1, prize_offset: 0, press_limit: Some(100) };
+
+    pub const PART2: Rules = Rules { a_cost: 3, b_cost: 1, prize_offset: 10_000_000_000_000, press_limit: None };
+}
+
+impl ClawMachine {
+    pub fn new(button_a: Coords, button_b: Coords, prize: Coords) -> Self {
+        Self { button_a, button_b, prize }
+    }
+
+    /// Cheapest presses of button A and button B winning the prize
+    pub fn presses(&self, rules: &Rules) -> Option<(isize, isize)> {
+        solver::solve(self, rules)
+    }
+
+    /// Fewest tokens winning the prize
+    pub fn tokens(&self, rules: &Rules) -> Option<usize> {
+        self.presses(rules)
+            .map(|(a, b)| (a * rules.a_cost + b * rules.b_cost) as usize)
+    }
+}
+
+/// Fewest tokens winning every prize that can be won
+pub fn total_tokens(machines: &[ClawMachine], rules: &Rules) -> usize {
+    #[cfg(feature = "parallel")]
+    let machines = machines.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let machines = machines.iter();
+    machines
+        .filter_map(|m| m.tokens(rules))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_rules() {
+        let machine = ClawMachine::new(Coords::new(94, 34), Coords::new(22, 67), Coords::new(8400, 5400));
+        assert_eq!(machine.tokens(&Rules::PART1), Some(280));
+
+        let rules = Rules { a_cost: 1, b_cost: 2, ..Rules::PART1 };
+        assert_eq!(machine.tokens(&rules), Some(160));
+
+        let rules = Rules { prize_offset: 94 + 22, ..Rules::PART1 };
+        assert_eq!(machine.presses(&rules), None, "The offset prize is off every reachable point");
+    }
+}
@@ -1,10 +1,9 @@
-use day_13::run;
-
-mod parser;
+use aoc_core::Solution;
+use day_13::Day13;
 
 fn main() {
     let content = include_str!("../input.txt");
-    let (_, arcades) = parser::parse_input(&content).expect("Expected parsed input");
-    println!("Part 1: {}", run(&arcades, false));
-    println!("Part 2: {}", run(&arcades, true));
-}
\ No newline at end of file
+    let input = Day13::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day13::part1(&input));
+    println!("Part 2: {}", Day13::part2(&input));
+}
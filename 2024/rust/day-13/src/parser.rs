@@ -1,41 +1,46 @@
+use aoc_core::{parser::{finish, tag, IResult}, ParseError};
 use nom::{
-    bytes::complete::tag,
     character::complete::{digit1, newline},
-    combinator::{eof, map, map_res},
+    combinator::{cut, eof, map, map_res},
     multi::separated_list1,
-    IResult,
     Parser
 };
 
-fn number(input: &str) -> IResult<&str, isize> {
+use crate::machine::{ClawMachine, Coords};
+
+fn number(input: &str) -> IResult<'_, isize> {
     map_res(digit1, str::parse).parse(input)
 }
 
-fn button_behavior(input: &str) -> IResult<&str, (isize, isize)> {
+fn button_behavior(input: &str) -> IResult<'_, Coords> {
     map(
         (tag("X+"), number, tag(", Y+"), number),
-        |(_, x, _, y)| (x, y)
+        |(_, x, _, y)| Coords::new(x, y)
     ).parse(input)
 }
 
-fn prize_coords(input: &str) -> IResult<&str, (isize, isize)> {
+fn prize_coords(input: &str) -> IResult<'_, Coords> {
     map(
         (tag("X="), number, tag(", Y="), number),
-        |(_, x, _, y)| (x, y)
+        |(_, x, _, y)| Coords::new(x, y)
     ).parse(input)
 }
 
-fn arcade(input: &str) -> IResult<&str, (isize, isize, isize, isize, isize, isize)> {
-    let (input, (_, (a, d), _)) = (tag("Button A: "), button_behavior, newline).parse(input)?;
-    let (input, (_, (b, e), _)) = (tag("Button B: "), button_behavior, newline).parse(input)?;
-    let (input, (_, (c, f), _)) = (tag("Prize: "), prize_coords, newline).parse(input)?;
-    Ok((input, (a, b, c, d, e, f)))
+fn claw_machine(input: &str) -> IResult<'_, ClawMachine> {
+    let (input, (_, button_a, _)) = (tag("Button A: "), button_behavior, newline).parse(input)?;
+    let (input, (_, button_b, _)) = (tag("Button B: "), button_behavior, newline).parse(input)?;
+    let (input, (_, prize, _)) = (tag("Prize: "), prize_coords, newline).parse(input)?;
+    Ok((input, ClawMachine::new(button_a, button_b, prize)))
 }
 
-pub fn parse_input(input: &str) -> IResult<&str, Vec<(isize, isize, isize, isize, isize, isize)>> {
-    let (input, arcades) = separated_list1(newline, arcade).parse(input)?;
+fn claw_machines(input: &str) -> IResult<'_, Vec<ClawMachine>> {
+    let (input, machines) = separated_list1(newline, cut(claw_machine)).parse(input)?;
     let (input, _) = eof(input)?;
-    Ok((input, arcades))
+    Ok((input, machines))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    finish(input, claw_machines(input))
 }
 
 #[cfg(test)]
@@ -46,24 +51,24 @@ mod tests {
     fn test_parse_button_behavior() {
         let input = "X+94, Y+34";
         let (_, result) = button_behavior(input).expect("Expecting successful parsing");
-        assert_eq!(result, (94, 34));
+        assert_eq!(result, Coords::new(94, 34));
     }
 
     #[test]
     fn test_parse_prize_coords() {
         let input = "X=8400, Y=5400";
         let (_, result) = prize_coords(input).expect("Expecting successful parsing");
-        assert_eq!(result, (8400, 5400));
+        assert_eq!(result, Coords::new(8400, 5400));
     }
 
     #[test]
-    fn test_parse_arcade() {
+    fn test_parse_claw_machine() {
         let input = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 ";
-        let (_, result) = arcade(input).expect("Expecting successful parsing");
-        assert_eq!(result, (94, 22, 8400, 34, 67, 5400));
+        let (_, result) = claw_machine(input).expect("Expecting successful parsing");
+        assert_eq!(result, ClawMachine::new(Coords::new(94, 34), Coords::new(22, 67), Coords::new(8400, 5400)));
     }
 
     #[test]
@@ -76,10 +81,22 @@ Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 ";
-        let (_, result) = parse_input(input).expect("Expecting successful parsing");
-        assert_eq!(result, vec![
-            (94, 22, 8400, 34, 67, 5400),
-            (94, 22, 8400, 34, 67, 5400),
-        ]);
+        let result = parse_input(input).expect("Expecting successful parsing");
+        let machine = ClawMachine::new(Coords::new(94, 34), Coords::new(22, 67), Coords::new(8400, 5400));
+        assert_eq!(result, vec![machine.clone(), machine]);
+    }
+
+    #[test]
+    fn test_parse_invalid_button() {
+        let input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+94, Y+34
+Button B: X+22 Y+67
+Prize: X=8400, Y=5400
+";
+        let result = parse_input(input);
+        assert_eq!(result.err(), Some(ParseError::new(6, 15, " Y+67", "', Y+'")));
     }
 }
@@ -0,0 +1,190 @@
+use crate::machine::{ClawMachine, Rules};
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) { q - 1 } else { q }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -div_floor(-a, b)
+}
+
+/// Values of `k` from `lo` to `hi`, a missing end being unbounded
+type Range = (Option<i128>, Option<i128>);
+
+/// Lower of two upper ends, a missing end being unbounded
+fn min_upper(a: Option<i128>, b: Option<i128>) -> Option<i128> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, None) | (None, a) => a,
+    }
+}
+
+/// Narrows the range to the `k` keeping `base + k*step` within `0..=limit`, `None` when no `k` is left
+fn bound((lo, hi): Range, base: i128, step: i128, limit: Option<i128>) -> Option<Range> {
+    let (lo, hi) = match step {
+        0 => return (base >= 0 && limit.is_none_or(|l| base <= l)).then_some((lo, hi)),
+        s if s > 0 => (lo.max(Some(div_ceil(-base, s))), min_upper(hi, limit.map(|l| div_floor(l - base, s)))),
+        s => (lo.max(limit.map(|l| div_ceil(l - base, s))), min_upper(hi, Some(div_floor(-base, s)))),
+    };
+    match (lo, hi) {
+        (Some(lo), Some(hi)) if lo > hi => None,
+        range => Some(range),
+    }
+}
+
+/// Cheapest non-negative presses with ax + by = c, `None` when there are none
+fn cheapest_on_line(a: i128, b: i128, c: i128, costs: (i128, i128), limit: Option<i128>) -> Option<(i128, i128)> {
+    let (g, p, q) = extended_gcd(a, b);
+    if g == 0 || c % g != 0 {
+        return (g == 0 && c == 0).then_some((0, 0));
+    }
+    // Every solution is (x0 + k*sx, y0 - k*sy)
+    let (x0, y0) = (p * (c / g), q * (c / g));
+    let (sx, sy) = (b / g, a / g);
+    let (lo, hi) = bound((None, None), x0, sx, limit).and_then(|range| bound(range, y0, -sy, limit))?;
+    // The cost changes linearly with k and grows towards an unbounded end, where a button
+    // is pressed ever more, so the cheapest presses sit at a bounded end of the range
+    let presses = |k: i128| (x0 + k * sx, y0 - k * sy);
+    let cost = |&(x, y): &(i128, i128)| x * costs.0 + y * costs.1;
+    [lo, hi]
+        .into_iter()
+        .flatten()
+        .map(presses)
+        .min_by_key(cost)
+}
+
+/// Cheapest non-negative presses x and y of the buttons solving ax + by = c and dx + ey = f,
+/// where button A moves (a, d), button B moves (b, e) and the prize sits at (c, f)
+pub fn solve(machine: &ClawMachine, rules: &Rules) -> Option<(isize, isize)> {
+    let ClawMachine { button_a, button_b, prize } = machine;
+    let [a, b, c, d, e, f] = [
+        button_a.x,
+        button_b.x,
+        prize.x + rules.prize_offset,
+        button_a.y,
+        button_b.y,
+        prize.y + rules.prize_offset,
+    ].map(|n| n as i128);
+    let costs = (rules.a_cost as i128, rules.b_cost as i128);
+    let limit = rules.press_limit.map(|l| l as i128);
+    let det = a * e - b * d;
+    let (x, y) = if det != 0 {
+        let (x, y) = (c * e - b * f, a * f - c * d);
+        if x % det != 0 || y % det != 0 {
+            return None;
+        }
+        (x / det, y / det)
+    } else if (a, b) != (0, 0) {
+        cheapest_on_line(a, b, c, costs, limit)?
+    } else {
+        cheapest_on_line(d, e, f, costs, limit)?
+    };
+
+    let within = |n: i128| n >= 0 && limit.is_none_or(|l| n <= l);
+    (within(x) && within(y) && a * x + b * y == c && d * x + e * y == f)
+        .then_some((x as isize, y as isize))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::machine::Coords;
+
+    fn machine(a: (isize, isize), b: (isize, isize), prize: (isize, isize)) -> ClawMachine {
+        ClawMachine::new(Coords::new(a.0, a.1), Coords::new(b.0, b.1), Coords::new(prize.0, prize.1))
+    }
+
+    const UNLIMITED: Rules = Rules { press_limit: None, ..Rules::PART1 };
+
+    fn brute_force(m: &ClawMachine, limit: isize) -> Option<usize> {
+        (0..=limit)
+            .flat_map(|x| (0..=limit).map(move |y| (x, y)))
+            .filter(|&(x, y)| {
+                x * m.button_a.x + y * m.button_b.x == m.prize.x
+                    && x * m.button_a.y + y * m.button_b.y == m.prize.y
+            })
+            .map(|(x, y)| (x * 3 + y) as usize)
+            .min()
+    }
+
+    #[test]
+    fn unique_solution() {
+        assert_eq!(solve(&machine((94, 34), (22, 67), (8400, 5400)), &Rules::PART1), Some((80, 40)));
+        assert_eq!(solve(&machine((26, 66), (67, 21), (12748, 12176)), &UNLIMITED), None);
+    }
+
+    #[test]
+    fn reject_negative_presses() {
+        // 2x + y = 1 and x + y = 2 only meet at x = -1
+        assert_eq!(solve(&machine((2, 1), (1, 1), (1, 2)), &UNLIMITED), None);
+    }
+
+    #[test]
+    fn press_limit() {
+        let m = machine((1, 0), (0, 1), (150, 10));
+        assert_eq!(solve(&m, &UNLIMITED), Some((150, 10)));
+        assert_eq!(solve(&m, &Rules::PART1), None);
+    }
+
+    #[test]
+    fn collinear_buttons() {
+        // B moves twice as far as A for a third of the tokens, so only B is pressed
+        assert_eq!(solve(&machine((1, 3), (2, 6), (10, 30)), &UNLIMITED), Some((0, 5)));
+        // B moves a quarter as far as A for a third of the tokens, so A is pressed as much as possible
+        assert_eq!(solve(&machine((4, 8), (1, 2), (10, 20)), &UNLIMITED), Some((2, 2)));
+        assert_eq!(solve(&machine((1, 3), (2, 6), (10, 31)), &UNLIMITED), None, "Prize off the line of the buttons");
+        assert_eq!(solve(&machine((2, 1), (4, 2), (11, 5)), &UNLIMITED), None, "Prize between reachable points");
+    }
+
+    #[test]
+    fn motionless_buttons() {
+        assert_eq!(solve(&machine((0, 0), (0, 0), (0, 0)), &UNLIMITED), Some((0, 0)));
+        assert_eq!(solve(&machine((0, 0), (0, 0), (1, 0)), &UNLIMITED), None);
+    }
+
+    #[test]
+    fn one_motionless_button() {
+        let m = machine((1, 2), (0, 0), (150, 300));
+        assert_eq!(solve(&m, &UNLIMITED), Some((150, 0)));
+        assert_eq!(solve(&m, &Rules::PART1), None);
+        assert_eq!(solve(&machine((0, 0), (1, 2), (15, 30)), &Rules::PART1), Some((0, 15)));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(a in (0..8isize, 0..8isize), b in (0..8isize, 0..8isize), x in 0..12isize, y in 0..12isize) {
+            let m = machine(a, b, (a.0 * x + b.0 * y, a.1 * x + b.1 * y));
+            let rules = Rules { press_limit: Some(20), ..Rules::PART1 };
+            prop_assert_eq!(m.tokens(&rules), brute_force(&m, 20));
+        }
+
+        #[test]
+        fn matches_brute_force_when_collinear(a in (0..8isize, 0..8isize), k in 1..4isize, scaled_a in any::<bool>(), px in 0..60isize) {
+            let (a, b) = if scaled_a { (a, (a.0 * k, a.1 * k)) } else { ((a.0 * k, a.1 * k), a) };
+            let py = if a.0 == 0 { px } else { px * a.1 / a.0 };
+            let m = machine(a, b, (px, py));
+            let rules = Rules { press_limit: Some(60), ..Rules::PART1 };
+            prop_assert_eq!(m.tokens(&rules), brute_force(&m, 60));
+        }
+
+        #[test]
+        fn matches_brute_force_with_a_motionless_button(moving in (0..8isize, 0..8isize), still_a in any::<bool>(), presses in 0..30isize) {
+            let (a, b) = if still_a { ((0, 0), moving) } else { (moving, (0, 0)) };
+            let m = machine(a, b, (moving.0 * presses, moving.1 * presses));
+            let rules = Rules { press_limit: Some(20), ..Rules::PART1 };
+            prop_assert_eq!(m.tokens(&rules), brute_force(&m, 20));
+        }
+    }
+}
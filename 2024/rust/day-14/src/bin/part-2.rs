@@ -1,6 +1,10 @@
-use day_14::prelude::part2;
+use aoc_core::Solution;
+use day_14::prelude::Day14;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    println!("Part 2: {}", part2(&content));
-}
\ No newline at end of file
+    let input = Day14::parse(content).expect("Expecting valid input");
+    let second = Day14::part2(&input);
+    input.print(second);
+    println!("Part 2: {second}");
+}
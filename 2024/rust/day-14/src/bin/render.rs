@@ -0,0 +1,64 @@
+use std::{fs, path::PathBuf};
+
+use aoc_core::Solution;
+use clap::Parser;
+use day_14::prelude::{Arena, Day14};
+
+/// Renders the day 14 robots as PPM images, one second or a contact sheet of many
+#[derive(Parser)]
+struct Args {
+    /// Image file to write
+    output: PathBuf,
+
+    /// Puzzle input, the committed input when omitted
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Arena of the robots: small, big or WIDTHxHEIGHT
+    #[arg(short, long, default_value = "big")]
+    arena: Arena,
+
+    /// First second rendered
+    #[arg(short, long, default_value_t = 0)]
+    second: usize,
+
+    /// Seconds rendered on a contact sheet, a single frame when 1
+    #[arg(short = 'n', long, default_value_t = 1)]
+    count: usize,
+
+    /// Seconds between consecutive frames of a contact sheet
+    #[arg(long, default_value_t = 1)]
+    step: usize,
+
+    /// Frames per row of a contact sheet
+    #[arg(short, long, default_value_t = 10)]
+    columns: usize,
+
+    /// Pixels per tile
+    #[arg(long, default_value_t = 2)]
+    scale: usize,
+}
+
+fn main() -> Result<(), String> {
+    let args = Args::parse();
+    let content = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?,
+        None => include_str!("../../input.txt").to_string(),
+    };
+    let mut map = Day14::parse(&content).map_err(|e| e.to_string())?;
+    map.arena = args.arena;
+
+    let seconds: Vec<usize> = (0..args.count.max(1))
+        .map(|i| args.second + i * args.step)
+        .collect();
+    let image = match seconds[..] {
+        [second] => map.image(second, args.scale),
+        _ => {
+            seconds
+                .chunks(args.columns.max(1))
+                .for_each(|row| println!("{}", row.iter().map(usize::to_string).collect::<Vec<_>>().join(" ")));
+            map.contact_sheet(seconds, args.columns, args.scale)
+        },
+    };
+    fs::write(&args.output, image.ppm()).map_err(|e| format!("Cannot write {}: {}", args.output.display(), e))
+}
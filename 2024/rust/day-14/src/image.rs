@@ -0,0 +1,120 @@
+use grid::{Grid, Point};
+
+const BACKGROUND: [u8; 3] = [0, 0, 0];
+const GUTTER: [u8; 3] = [64, 64, 64];
+
+/// RGB picture of robot frames
+#[derive(Debug, PartialEq)]
+pub struct Image {
+    pixels: Grid<[u8; 3]>,
+}
+
+/// Brighter green the more robots share a tile
+fn color(robots: usize) -> [u8; 3] {
+    match robots {
+        0 => BACKGROUND,
+        n => [0, (95 + 40 * n.min(4)) as u8, 0],
+    }
+}
+
+impl Image {
+    fn new(width: usize, height: usize, pixel: [u8; 3]) -> Self {
+        Self { pixels: Grid::new(width, height, pixel) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        self.pixels[Point::new(x, y)]
+    }
+
+    fn fill(&mut self, x: usize, y: usize, size: usize, pixel: [u8; 3]) {
+        for py in y..y + size {
+            for px in x..x + size {
+                self.pixels[Point::new(px, py)] = pixel;
+            }
+        }
+    }
+
+    /// Copies `image` with its top left corner at `(left, top)`
+    fn paste(&mut self, left: usize, top: usize, image: &Image) {
+        for (p, &pixel) in image.pixels.iter() {
+            self.pixels[Point::new(left + p.x, top + p.y)] = pixel;
+        }
+    }
+
+    /// Frame of the robots, every tile drawn as a `scale`×`scale` square
+    pub fn frame(frequency: &Grid<usize>, scale: usize) -> Self {
+        let mut image = Self::new(frequency.width() * scale, frequency.height() * scale, BACKGROUND);
+        for (p, &robots) in frequency.iter() {
+            image.fill(p.x * scale, p.y * scale, scale, color(robots));
+        }
+        image
+    }
+
+    /// Frames laid out row by row in `columns` columns, split by a one pixel gutter
+    pub fn sheet(frames: &[Image], columns: usize) -> Self {
+        let Some(first) = frames.first() else {
+            return Self::new(0, 0, GUTTER);
+        };
+        let columns = columns.clamp(1, frames.len());
+        let rows = frames.len().div_ceil(columns);
+        let (cell_width, cell_height) = (first.width() + 1, first.height() + 1);
+        let mut sheet = Self::new(columns * cell_width - 1, rows * cell_height - 1, GUTTER);
+        for (i, frame) in frames.iter().enumerate() {
+            sheet.paste(i % columns * cell_width, i / columns * cell_height, frame);
+        }
+        sheet
+    }
+
+    /// Binary PPM encoding of the image
+    pub fn ppm(&self) -> Vec<u8> {
+        self.pixels.ppm(1, |_, &pixel| pixel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frequency() -> Grid<usize> {
+        let mut frequency = Grid::new(3, 2, 0);
+        frequency[Point::new(1, 0)] = 1;
+        frequency[Point::new(2, 1)] = 2;
+        frequency
+    }
+
+    #[test]
+    fn scaled_frame() {
+        let image = Image::frame(&frequency(), 2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.pixel(0, 0), BACKGROUND);
+        assert_eq!(image.pixel(3, 1), color(1));
+        assert_eq!(image.pixel(5, 3), color(2));
+    }
+
+    #[test]
+    fn contact_sheet() {
+        let frames: Vec<_> = (0..3).map(|_| Image::frame(&frequency(), 1)).collect();
+        let sheet = Image::sheet(&frames, 2);
+        assert_eq!((sheet.width(), sheet.height()), (7, 5));
+        assert_eq!(sheet.pixel(3, 0), GUTTER);
+        assert_eq!(sheet.pixel(5, 0), color(1));
+        assert_eq!(sheet.pixel(2, 4), color(2));
+        assert_eq!(sheet.pixel(6, 4), GUTTER, "Unused cells stay blank");
+    }
+
+    #[test]
+    fn ppm_encoding() {
+        let ppm = Image::frame(&frequency(), 1).ppm();
+        let header = b"P6\n3 2\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 3 * 2 * 3);
+    }
+}
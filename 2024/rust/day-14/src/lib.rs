@@ -1,10 +1,15 @@
+mod image;
 mod map;
 mod parser;
-mod point;
 mod robot;
 mod simulation;
+mod tree;
 
 pub mod prelude {
-    pub use crate::parser::parse_input;
-    pub use crate::simulation::{run, part1, part2};
+    pub use crate::{
+        image::Image,
+        map::{Arena, Map},
+        simulation::Day14,
+        tree::{Detector, TreeError},
+    };
 }
\ No newline at end of file
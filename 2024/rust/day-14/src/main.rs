@@ -1,8 +1,11 @@
-use day_14::prelude::*;
+use aoc_core::Solution;
+use day_14::prelude::Day14;
 
 fn main() {
     let content = include_str!("../input.txt");
-    let (_, map) = parse_input(&content).expect("Expected parsed input");
-    println!("Part 1: {}", run(&map, false));
-    println!("Part 2: {}", run(&map, true));
-}
\ No newline at end of file
+    let input = Day14::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day14::part1(&input));
+    let second = Day14::part2(&input);
+    input.print(second);
+    println!("Part 2: {second}");
+}
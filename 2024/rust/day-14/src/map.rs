@@ -1,34 +1,74 @@
-use crate::point::Point;
-use crate::robot::Robot;
+use std::str::FromStr;
 
-pub struct Map {
+use grid::{Grid, Point};
+
+use crate::{
+    image::Image,
+    robot::Robot,
+    tree::{self, Detector, TreeError},
+};
+
+/// Size of the area patrolled by the robots
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arena {
     pub width: usize,
     pub height: usize,
+}
+
+impl Arena {
+    /// Arena of the puzzle examples
+    pub const SMALL: Arena = Arena { width: 11, height: 7 };
+
+    /// Arena of the puzzle input
+    pub const BIG: Arena = Arena { width: 101, height: 103 };
+}
+
+impl FromStr for Arena {
+    type Err = String;
+
+    /// Parses `small`, `big` or `WIDTHxHEIGHT`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "small" => Ok(Arena::SMALL),
+            "big" => Ok(Arena::BIG),
+            _ => s
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                .filter(|&(width, height)| width > 0 && height > 0)
+                .map(|(width, height)| Arena { width, height })
+                .ok_or("expected small, big or WIDTHxHEIGHT".to_string()),
+        }
+    }
+}
+
+pub struct Map {
+    pub arena: Arena,
+    pub detector: Detector,
     pub robots: Vec<Robot>,
 }
 
 impl Map {
-    fn new(width: usize, height: usize, robots: Vec<Robot>) -> Self {
-        Self { width, height, robots }
+    pub fn new(arena: Arena, robots: Vec<Robot>) -> Self {
+        Self { arena, detector: Detector::default(), robots }
     }
 
     pub fn small(robots: Vec<Robot>) -> Self {
-        Self::new(11, 7, robots)
+        Self::new(Arena::SMALL, robots)
     }
 
     pub fn big(robots: Vec<Robot>) -> Self {
-        Self::new(101, 103, robots)
+        Self::new(Arena::BIG, robots)
     }
 
     pub fn safety_factor(&self, seconds: usize) -> usize {
         let mut quadrants = [0; 4];
-        let hx = self.width / 2;
-        let hy = self.height / 2;
+        let hx = self.arena.width / 2;
+        let hy = self.arena.height / 2;
         
         self.robots
             .iter()
-            .map(|r| r.step_n(self.width, self.height, seconds))
-            .filter_map(|p| p.quadrant(hx, hy))
+            .map(|r| r.step_n(self.arena.width, self.arena.height, seconds))
+            .filter_map(|p| quadrant(p, hx, hy))
             .for_each(|q| quadrants[q] += 1);
 
         quadrants
@@ -36,41 +76,124 @@ impl Map {
             .product()
     }
 
-    pub fn xmas_tree(&self) -> usize {
-        for second in 0..100_000 {
-            let points: Vec<Point> = self.robots
-                .iter()
-                .map(|r| r.step_n(self.width, self.height, second))
-                .collect();
-    
-            let mut frequency = vec![vec![0usize; self.width]; self.height];
-            for p in points {
-                frequency[p.y][p.x] += 1;
-            }
-    
-            for y in 0..self.height-3 {
-                for x in 0..self.width-3 {
-                    let block = (y..y+3).all(|y| (x..x+3).all(|x| frequency[y][x] > 0));
-                    if block {
-                        self.print(&frequency);
-                        return second
-                    }
-                }
+    fn frequency(&self, second: usize) -> Grid<usize> {
+        let Arena { width, height } = self.arena;
+        let mut frequency = Grid::new(width, height, 0);
+        self.robots
+            .iter()
+            .for_each(|r| frequency[r.step_n(width, height, second)] += 1);
+        frequency
+    }
+
+    /// First second showing a solid 3×3 block of robots, within the `width × height`
+    /// seconds after which the robots are back where they started
+    pub fn xmas_tree(&self) -> Result<usize, TreeError> {
+        if self.robots.is_empty() {
+            return Err(TreeError::NoRobots);
+        }
+        let period = self.arena.width * self.arena.height;
+        for second in 0..period {
+            let frequency = self.frequency(second);
+            let block = frequency
+                .points()
+                .any(|p| (0..3).all(|dy| (0..3).all(|dx| frequency
+                    .offset(p, dx, dy)
+                    .is_some_and(|q| frequency[q] > 0))));
+            if block {
+                return Ok(second)
             }
         }
+        Err(TreeError::NotFound(period))
+    }
 
-        panic!("Christmas tree not found")
+    /// Second of the Christmas tree found in `width + height` simulations, as the robots
+    /// cluster along x every `width` seconds and along y every `height` seconds
+    pub fn xmas_tree_by_variance(&self) -> Result<usize, TreeError> {
+        if self.robots.is_empty() {
+            return Err(TreeError::NoRobots);
+        }
+        let Arena { width, height } = self.arena;
+        let axis = |second: usize, coordinate: fn(Point) -> usize| self.robots
+            .iter()
+            .map(|r| coordinate(r.step_n(width, height, second)))
+            .collect();
+        let x = tree::clustered_second(width, |second| axis(second, |p| p.x))
+            .ok_or(TreeError::NoCluster('x'))?;
+        let y = tree::clustered_second(height, |second| axis(second, |p| p.y))
+            .ok_or(TreeError::NoCluster('y'))?;
+        tree::crt(x, width, y, height).ok_or(TreeError::NoCommonSecond { x, y })
     }
 
-    fn print(&self, frequency: &[Vec<usize>]) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                match frequency[y][x] {
-                    0 => print!(" "),
-                    n => print!("{n}"),
-                }
-            }
-            println!();
+    /// Image of the robots after `second` seconds, every tile drawn as a `scale`×`scale` square
+    pub fn image(&self, second: usize, scale: usize) -> Image {
+        Image::frame(&self.frequency(second), scale)
+    }
+
+    /// Images of the robots after each of `seconds`, laid out in `columns` columns
+    pub fn contact_sheet(&self, seconds: impl IntoIterator<Item = usize>, columns: usize, scale: usize) -> Image {
+        let frames: Vec<_> = seconds
+            .into_iter()
+            .map(|second| self.image(second, scale))
+            .collect();
+        Image::sheet(&frames, columns)
+    }
+
+    /// Prints how many robots there are on each tile after `second` seconds
+    pub fn print(&self, second: usize) {
+        let text = self.frequency(second).render(|_, &n| match n {
+            0 => ' ',
+            n => char::from_digit(n.min(9) as u32, 10).expect("Expected a single digit"),
+        });
+        print!("{text}");
+    }
+}
+
+fn quadrant(p: Point, hx: usize, hy: usize) -> Option<usize> {
+    if p.x == hx || p.y == hy {
+        None
+    } else {
+        let left_x = p.x < hx;
+        let top_y = p.y < hy;
+        match (left_x, top_y) {
+            (true, true)   => Some(0),
+            (false, true)  => Some(1),
+            (true, false)  => Some(2),
+            (false, false) => Some(3),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_arena() {
+        assert_eq!("small".parse(), Ok(Arena::SMALL));
+        assert_eq!("big".parse(), Ok(Arena::BIG));
+        assert_eq!("5x3".parse(), Ok(Arena { width: 5, height: 3 }));
+        assert!("0x3".parse::<Arena>().is_err());
+        assert!("5by3".parse::<Arena>().is_err());
+    }
+
+    #[test]
+    fn tree_by_variance() {
+        let velocities: [(isize, isize); 8] = [(1, 2), (-3, 1), (2, -2), (4, 3), (-1, -3), (3, 0), (0, 1), (-2, 2)];
+        let robots = velocities
+            .iter()
+            .map(|&(dx, dy)| {
+                let x = (5 - dx * 20).rem_euclid(11) as usize;
+                let y = (3 - dy * 20).rem_euclid(7) as usize;
+                Robot::new(x, y, dx, dy)
+            })
+            .collect();
+        let map = Map::small(robots);
+        assert_eq!(map.xmas_tree_by_variance(), Ok(20));
+    }
+
+    #[test]
+    fn tree_without_robots() {
+        let map = Map::small(Vec::new());
+        assert_eq!(map.xmas_tree_by_variance(), Err(TreeError::NoRobots));
+    }
+}
@@ -1,38 +1,35 @@
+use aoc_core::{parser::{finish, tag, IResult}, ParseError};
 use nom::{
-    bytes::complete::tag,
-    character::complete::{char, isize, newline, usize},
-    combinator::{eof, map},
-    multi::many1, sequence::{preceded, separated_pair, terminated},
-    IResult,
+    character::complete::{char, isize, usize},
+    combinator::{all_consuming, map},
+    sequence::{preceded, separated_pair},
     Parser
 };
 
 use crate::robot::Robot;
-use crate::map::Map;
+use crate::map::{Arena, Map};
 
-fn velocity(input: &str) -> IResult<&str, (isize, isize)> {
+fn velocity(input: &str) -> IResult<'_, (isize, isize)> {
     separated_pair(isize, char(','), isize).parse(input)
 }
 
-fn position(input: &str) -> IResult<&str, (usize, usize)> {
+fn position(input: &str) -> IResult<'_, (usize, usize)> {
     separated_pair(usize, char(','), usize).parse(input)
 }
 
-fn robot(input: &str) -> IResult<&str, Robot> {
+fn robot(input: &str) -> IResult<'_, Robot> {
     map(
-        terminated((preceded( tag("p="), position), preceded(tag(" v="), velocity)), newline),
+        (preceded(tag("p="), position), preceded(tag(" v="), velocity)),
         |((x, y), (dx, dy))| Robot::new(x, y, dx, dy)
     ).parse(input)
 }
 
-pub fn parse_input(input: &str) -> IResult<&str, Map> {
-    let (input, robots) = terminated(many1(robot), eof).parse(input)?;
-    let map = if cfg!(test) {
-        Map::small(robots)
-    } else {
-        Map::big(robots)
-    };
-    Ok((input, map))
+pub fn parse_input(input: &str, arena: Arena) -> Result<Map, ParseError> {
+    let robots = input
+        .lines()
+        .map(|line| finish(input, all_consuming(robot).parse(line)))
+        .collect::<Result<_, _>>()?;
+    Ok(Map::new(arena, robots))
 }
 
 #[cfg(test)]
@@ -41,7 +38,7 @@ mod tests {
 
     #[test]
     fn robot_line() {
-        let (_, robot) = robot("p=6,3 v=-1,-3\n").expect("Expecting successful parsing");
+        let (_, robot) = robot("p=6,3 v=-1,-3").expect("Expecting successful parsing");
         assert_eq!(robot, Robot::new(6, 3, -1, -3));
     }
 
@@ -51,10 +48,21 @@ mod tests {
 p=0,4 v=3,-3
 p=6,3 v=-1,-3
 ";
-        let (_, map) = parse_input(input).expect("Expecting successful parsing");
+        let map = parse_input(input, Arena::SMALL).expect("Expecting successful parsing");
+        assert_eq!(map.arena, Arena::SMALL);
         assert_eq!(map.robots, vec![
             Robot::new(0, 4, 3, -3),
             Robot::new(6, 3, -1, -3)
         ]);
     }
+
+    #[test]
+    fn invalid_velocity() {
+        let input = "\
+p=0,4 v=3,-3
+p=6,3 v=-1;-3
+";
+        let result = parse_input(input, Arena::SMALL);
+        assert_eq!(result.err(), Some(ParseError::new(2, 11, ";-3", "','")));
+    }
 }
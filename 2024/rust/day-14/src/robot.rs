@@ -1,4 +1,4 @@
-use crate::point::Point;
+use grid::Point;
 
 #[derive(Debug, PartialEq)]
 struct Velocity {
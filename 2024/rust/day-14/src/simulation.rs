@@ -1,22 +1,49 @@
-use crate::map::Map;
+use aoc_core::{Options, ParseError, Solution};
+
+use crate::map::{Arena, Map};
 use crate::parser;
+use crate::tree::{Detector, TreeError};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
 
-pub fn run(map: &Map, easter_egg: bool) -> usize {
-    if easter_egg {
-        map.xmas_tree()
-    } else {
+    type Input = Map;
+    type Answer = usize;
+
+    const OPTIONS: &'static [&'static str] = &["arena", "detector"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input, Arena::BIG)
+    }
+
+    fn configure(mut map: Self::Input, options: &Options) -> Result<Self::Input, String> {
+        if let Some(arena) = options.get("arena")? {
+            map.arena = arena;
+        }
+        if let Some(detector) = options.get("detector")? {
+            map.detector = detector;
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
         map.safety_factor(100)
     }
-}
 
-pub fn part1(input: &str) -> usize {
-    let (_, map) = parser::parse_input(input).expect("Expecting parsed input");
-    run(&map, false)
-}
+    /// Second of the first Christmas tree, panicking when `try_part2` finds none
+    fn part2(map: &Self::Input) -> usize {
+        Self::try_part2(map).unwrap_or_else(|e| panic!("Day 14 part 2 has no answer: {e}"))
+    }
 
-pub fn part2(input: &str) -> usize {
-    let (_, map) = parser::parse_input(input).expect("Expecting parsed input");
-    run(&map, true)
+    fn try_part2(map: &Self::Input) -> Result<usize, String> {
+        let second = match map.detector {
+            Detector::Block => map.xmas_tree(),
+            Detector::Variance => map.xmas_tree_by_variance(),
+        };
+        second.map_err(|e: TreeError| e.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -40,7 +67,34 @@ p=9,5 v=-3,-3
 
     #[test]
     fn test_part1() {
-        let result = part1(INPUT);
+        let result = Day14::part1(&parser::parse_input(INPUT, Arena::SMALL).expect("Expecting successful parsing"));
         assert_eq!(result, 12);
     }
+
+    #[test]
+    fn test_no_tree() {
+        for detector in [Detector::Block, Detector::Variance] {
+            let mut empty = Day14::parse("").expect("Expecting successful parsing");
+            empty.detector = detector;
+            assert_eq!(Day14::try_part2(&empty), Err(TreeError::NoRobots.to_string()));
+        }
+        let treeless = parser::parse_input(INPUT, Arena::SMALL).expect("Expecting successful parsing");
+        assert_eq!(Day14::try_part2(&treeless), Err(TreeError::NotFound(77).to_string()));
+
+        let mut still = parser::parse_input("p=0,0 v=0,0\np=5,3 v=0,0\np=10,6 v=0,0\n", Arena::SMALL)
+            .expect("Expecting successful parsing");
+        still.detector = Detector::Variance;
+        assert_eq!(Day14::try_part2(&still), Err(TreeError::NoCluster('x').to_string()));
+    }
+
+    #[test]
+    fn test_configure_arena() {
+        let map = Day14::parse(INPUT).expect("Expecting successful parsing");
+        assert_eq!(map.arena, Arena::BIG);
+
+        let options = [Options::pair("arena=small").expect("Expecting a pair")].into_iter().collect();
+        let map = Day14::configure(map, &options).expect("Expecting a valid arena");
+        assert_eq!(map.arena, Arena::SMALL);
+        assert_eq!(Day14::part1(&map), 12);
+    }
 }
\ No newline at end of file
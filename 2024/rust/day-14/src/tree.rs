@@ -0,0 +1,118 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Lowest ratio between the variance of the tree frame and the mean variance of all frames
+const CLUSTER_RATIO: f64 = 0.75;
+
+/// Way of spotting the second the robots draw a Christmas tree
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Detector {
+    /// Simulates every second until a solid 3×3 block of robots shows up
+    #[default]
+    Block,
+    /// Finds the seconds the robots cluster on each axis and joins them with the CRT
+    Variance,
+}
+
+impl FromStr for Detector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "block" => Ok(Detector::Block),
+            "variance" => Ok(Detector::Variance),
+            _ => Err("expected block or variance".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TreeError {
+    NoRobots,
+    /// No second in the period clusters the robots along this axis
+    NoCluster(char),
+    /// The seconds found for each axis never happen at the same time
+    NoCommonSecond { x: usize, y: usize },
+    /// No block of robots shows up before the robots repeat after this many seconds
+    NotFound(usize),
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::NoRobots => write!(f, "no robots to draw a Christmas tree"),
+            TreeError::NoCluster(axis) => write!(f, "robots never cluster along the {axis} axis"),
+            TreeError::NoCommonSecond { x, y } =>
+                write!(f, "robots cluster along x at second {x} and along y at second {y}, but never both"),
+            TreeError::NotFound(period) => write!(f, "no Christmas tree in the {period} seconds before the robots repeat"),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
+pub fn variance(values: &[usize]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<usize>() as f64 / n;
+    values
+        .iter()
+        .map(|&v| (v as f64 - mean).powi(2))
+        .sum::<f64>() / n
+}
+
+/// Second of the `period` whose positions have the lowest variance, if clearly below the rest
+pub fn clustered_second(period: usize, positions: impl Fn(usize) -> Vec<usize>) -> Option<usize> {
+    let variances: Vec<f64> = (0..period)
+        .map(|second| variance(&positions(second)))
+        .collect();
+    let mean = variances.iter().sum::<f64>() / period as f64;
+    let (second, &lowest) = variances
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    (lowest < mean * CLUSTER_RATIO).then_some(second)
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// Smallest `t` with `t ≡ a (mod m)` and `t ≡ b (mod n)`, `None` when the congruences disagree
+pub fn crt(a: usize, m: usize, b: usize, n: usize) -> Option<usize> {
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+    let (g, p, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = m / g * n;
+    let t = a + (b - a) / g * p % (n / g) * m;
+    Some(t.rem_euclid(lcm) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(71, 101, 16, 103), Some(8050));
+        assert_eq!(crt(2, 3, 3, 5), Some(8));
+        assert_eq!(crt(1, 4, 3, 6), Some(9));
+        assert_eq!(crt(1, 4, 2, 6), None);
+    }
+
+    #[test]
+    fn lowest_variance() {
+        assert_eq!(variance(&[2, 4, 4, 4, 5, 5, 7, 9]), 4.0);
+        let spread = |second: usize| if second == 3 { vec![5, 5, 6, 6] } else { vec![0, 3, 7, 10] };
+        assert_eq!(clustered_second(7, spread), Some(3));
+        assert_eq!(clustered_second(7, |_| vec![0, 3, 7, 10]), None);
+    }
+}
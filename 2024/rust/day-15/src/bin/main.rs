@@ -1,8 +1,9 @@
-use day_15::prelude::{part1, part2, parse_input};
+use aoc_core::Solution;
+use day_15::prelude::Day15;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    let (mut map, dirs) = parse_input(content);
-    println!("Part 1: {}", part1(&mut map, &dirs));
-    println!("Part 2: {}", part2(&mut map, &dirs));
-}
\ No newline at end of file
+    let input = Day15::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day15::part1(&input));
+    println!("Part 2: {}", Day15::part2(&input));
+}
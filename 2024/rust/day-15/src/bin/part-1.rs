@@ -1,7 +1,8 @@
-use day_15::prelude::{part1, parse_input};
+use aoc_core::Solution;
+use day_15::prelude::Day15;
 
 fn main() {
     let content = include_str!("../../input.txt");
-    let (mut map, dirs) = parse_input(content);
-    println!("Part 1: {}", part1(&mut map, &dirs));
-}
\ No newline at end of file
+    let input = Day15::parse(content).expect("Expecting valid input");
+    println!("Part 1: {}", Day15::part1(&input));
+}
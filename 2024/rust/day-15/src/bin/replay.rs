@@ -0,0 +1,76 @@
+use std::{fs, path::PathBuf};
+
+use aoc_core::Solution;
+use clap::{Parser, ValueEnum};
+use day_15::prelude::{Day15, Replay};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Cast,
+    Ppm,
+}
+
+/// Replays the robot moves of day 15, one frame per move
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, the committed input when omitted
+    input: Option<PathBuf>,
+
+    /// Replays the widened warehouse of part 2
+    #[arg(short, long)]
+    wide: bool,
+
+    /// Prints only the map after this many moves
+    #[arg(short, long)]
+    step: Option<usize>,
+
+    /// Format of the exported frames
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// File (or directory of PPM frames) to export to, stdout when omitted
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Seconds between frames of an asciicast
+    #[arg(long, default_value_t = 0.1)]
+    interval: f64,
+
+    /// Pixels per cell of a PPM frame
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+}
+
+fn main() -> Result<(), String> {
+    let args = Args::parse();
+    let content = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?,
+        None => include_str!("../../input.txt").to_string(),
+    };
+    let (map, dirs) = Day15::parse(&content).map_err(|e| e.to_string())?;
+    let map = if args.wide { map.widen() } else { map };
+    let replay = Replay::record(&map, &dirs);
+
+    if let Some(step) = args.step {
+        let map = replay.seek(step).ok_or(format!("Step {step} is past the last move {}", replay.steps()))?;
+        map.print();
+        return Ok(());
+    }
+
+    let frames = match args.format {
+        Format::Text => replay.text(),
+        Format::Cast => replay.asciicast(args.interval),
+        Format::Ppm => {
+            let dir = args.output.ok_or("PPM frames need an output directory")?;
+            return replay.write_ppm(&dir, args.scale).map_err(|e| format!("Cannot write {}: {e}", dir.display()));
+        },
+    };
+    match args.output {
+        Some(path) => fs::write(&path, frames).map_err(|e| format!("Cannot write {}: {e}", path.display())),
+        None => {
+            print!("{frames}");
+            Ok(())
+        },
+    }
+}
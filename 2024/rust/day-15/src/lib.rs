@@ -1,9 +1,8 @@
-mod direction;
 mod map;
 mod parser;
+mod replay;
 mod simulation;
 
 pub mod prelude {
-    pub use crate::parser::parse_input;
-    pub use crate::simulation::{part1, part2};
+    pub use crate::{map::Map, replay::Replay, simulation::Day15};
 }
\ No newline at end of file
@@ -1,104 +1,184 @@
-use crate::direction::{Direction, DIRECTIONS};
+use std::mem;
 
-#[derive(Debug, PartialEq)]
+use grid::{Direction, Grid, Point};
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum Item {
     Wall,
     Box,
+    BoxLeft,
+    BoxRight,
     Empty,
 }
 
+#[derive(Clone)]
 pub struct Map {
-    pub robot: (usize, usize),
-    pub items: Vec<Vec<Item>>,
+    pub robot: Point,
+    pub items: Grid<Item>,
 }
 
 impl Map {
-    pub fn new(robot: (usize, usize), items: Vec<Vec<Item>>) -> Self {
+    pub fn new(robot: Point, items: Grid<Item>) -> Self {
         Self { robot, items }
     }
 
-    fn find_empty_item(&self, x: usize, y: usize, dir: Direction) -> Option<(usize, usize)> {
-        let (dy, dx) = DIRECTIONS[dir as usize];
-        let mut x = x as isize;
-        let mut y = y as isize;
-        loop {
-            if 0 <= y && y < self.items.len() as isize &&
-               0 <= x && x < self.items[y as usize].len() as isize {
-                match self.items[y as usize][x as usize] {
-                    Item::Empty => return Some((x as usize, y as usize)),
-                    Item::Wall => return None,
-                    Item::Box => {
-                        x += dx;
-                        y += dy;
-                    }
-                }
-            } else {
-                return None;
-            }
+    /// Map twice as wide, where every box spans two cells
+    pub fn widen(&self) -> Self {
+        let mut items = Grid::new(self.items.width() * 2, self.items.height(), Item::Empty);
+        for (p, item) in self.items.iter() {
+            let (left, right) = match item {
+                Item::Wall => (Item::Wall, Item::Wall),
+                Item::Box | Item::BoxLeft | Item::BoxRight => (Item::BoxLeft, Item::BoxRight),
+                Item::Empty => (Item::Empty, Item::Empty),
+            };
+            items[Point::new(p.x * 2, p.y)] = left;
+            items[Point::new(p.x * 2 + 1, p.y)] = right;
         }
+        Self::new(Point::new(self.robot.x * 2, self.robot.y), items)
     }
 
-    pub fn attempt_move(&mut self, dir: Direction) {
-        let (dy, dx) = DIRECTIONS[dir as usize];
-        let x = self.robot.0 as isize + dx;
-        let y = self.robot.1 as isize + dy;
-        if y >= 0 && x >= 0 {
-            let x = x as usize;
-            let y = y as usize;
-            if y < self.items.len() && x < self.items[y].len() {
-                match self.items[y][x] {
-                    Item::Empty => self.robot = (x, y),
-                    Item::Wall => (),
-                    Item::Box =>
-                        if let Some((tx, ty)) = self.find_empty_item(x, y, dir) {
-                            self.robot = (x, y);
-                            self.items[y][x] = Item::Empty;
-                            self.items[ty][tx] = Item::Box;
-                        }
-                }
+    /// Cells of every box pushed when moving into `p`, `None` when a wall blocks the push
+    fn pushed_cells(&self, p: Point, dir: Direction) -> Option<Vec<Point>> {
+        let mut cells = Vec::new();
+        let mut pending = vec![p];
+        while let Some(p) = pending.pop() {
+            if cells.contains(&p) {
+                continue;
+            }
+            match self.items[p] {
+                Item::Empty => continue,
+                Item::Wall => return None,
+                Item::Box => (),
+                Item::BoxLeft if dir.is_vertical() => pending.push(self.items.step(p, Direction::Right)?),
+                Item::BoxRight if dir.is_vertical() => pending.push(self.items.step(p, Direction::Left)?),
+                Item::BoxLeft | Item::BoxRight => (),
             }
+            cells.push(p);
+            pending.push(self.items.step(p, dir)?);
+        }
+        Some(cells)
+    }
+
+    /// Moves the robot unless a wall blocks it, returning the cells whose item changed
+    pub fn attempt_move(&mut self, dir: Direction) -> Vec<Point> {
+        let Some(p) = self.items.step(self.robot, dir) else { return Vec::new() };
+        let Some(cells) = self.pushed_cells(p, dir) else { return Vec::new() };
+        let moved: Vec<_> = cells
+            .into_iter()
+            .map(|c| (c, mem::replace(&mut self.items[c], Item::Empty)))
+            .collect();
+        let mut changed = Vec::with_capacity(moved.len() * 2);
+        for (c, item) in moved {
+            let t = self.items.step(c, dir).expect("Expecting pushed box inside the map");
+            self.items[t] = item;
+            changed.extend([c, t]);
         }
+        changed.sort_unstable();
+        changed.dedup();
+        self.robot = p;
+        changed
+    }
+
+    pub fn render(&self) -> String {
+        self.items.render(|p, item|
+            if p == self.robot {
+                '@'
+            } else {
+                match item {
+                    Item::Wall  => '#',
+                    Item::Box   => 'O',
+                    Item::BoxLeft  => '[',
+                    Item::BoxRight => ']',
+                    Item::Empty => '.',
+                }
+            })
     }
 
     pub fn print(&self) {
-        println!("{}", "#".repeat(self.items[0].len() + 2));
-        self.items
-            .iter()
-            .enumerate()
-            .for_each(|(y, row)| {
-                print!("#");
-                row
-                    .iter()
-                    .enumerate()
-                    .for_each(|(x, item)|
-                        if (x, y) == self.robot {
-                            print!("@");
-                        } else {
-                            match item {
-                                Item::Wall  => print!("#"),
-                                Item::Box   => print!("O"),
-                                Item::Empty => print!("."),
-                            }
-                        });
-                println!("#");
-            });
-        println!("{}", "#".repeat(self.items[0].len() + 2));
+        print!("{}", self.render());
+    }
+
+    /// Binary PPM image of the map, every cell drawn as a `scale`×`scale` square
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        self.items.ppm(scale, |p, item|
+            if p == self.robot {
+                [220, 50, 47]
+            } else {
+                match item {
+                    Item::Wall => [88, 88, 88],
+                    Item::Box | Item::BoxLeft | Item::BoxRight => [181, 137, 0],
+                    Item::Empty => [0, 0, 0],
+                }
+            })
     }
 
     pub fn gps_score(&self) -> usize {
         self.items
             .iter()
-            .enumerate()
-            .map(|(y, row)|
-                row
-                .iter()
-                .enumerate()
-                .map(|(x, item)|
-                    match item {
-                        Item::Box => (y + 1) * 100 + x + 1,
-                        _ => 0
-                    })
-                .sum::<usize>())
+            .map(|(p, item)|
+                match item {
+                    Item::Box | Item::BoxLeft => p.y * 100 + p.x,
+                    _ => 0
+                })
             .sum()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_input;
+
+    #[test]
+    fn test_widen_and_push() {
+        let input = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let (map, dirs) = parse_input(input).expect("Expecting successful parsing");
+        let mut map = map.widen();
+        assert_eq!(map.render(), "\
+##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############
+");
+
+        dirs.iter().for_each(|&dir| { map.attempt_move(dir); });
+        assert_eq!(map.render(), "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+");
+    }
+
+    #[test]
+    fn test_blocked_tree_push() {
+        let input = "\
+########
+#......#
+#..#...#
+#..[][]#
+#...[].#
+#....@.#
+########
+
+^";
+        let (mut map, dirs) = parse_input(input).expect("Expecting successful parsing");
+        let before = map.render();
+        dirs.iter().for_each(|&dir| { map.attempt_move(dir); });
+        assert_eq!(map.render(), before, "A wall above one branch of the tree blocks every box");
+    }
+}
@@ -1,56 +1,72 @@
-use crate::{direction::Direction, map::{Item, Map}};
+use aoc_core::ParseError;
+use grid::{Direction, Grid};
 
-fn parse_directions(input: &str) -> Vec<Direction> {
+use crate::map::{Item, Map};
+
+fn parse_directions(input: &str) -> Result<Vec<Direction>, ParseError> {
     input
         .lines()
-        .skip_while(|&line| line.len() > 0)
+        .skip_while(|&line| !line.is_empty())
         .skip(1)
-        .map(|line| line
-            .as_bytes()
-            .iter()
-            .map(|&b| Direction::from(b))
-            .collect::<Vec<Direction>>())
-        .flatten()
+        .flat_map(|line| line
+            .char_indices()
+            .map(move |(i, c)| Direction::try_from(c)
+                .map_err(|_| ParseError::at(input, &line[i..], "direction"))))
         .collect()
 }
 
-fn parse_map(input: &str) -> Map {
-    let lines: Vec<&str> = input
+/// Rejects a second robot, and a box half not next to its other half
+fn check_cells(input: &str, map: &str) -> Result<(), ParseError> {
+    let mut robot = false;
+    for line in map.lines() {
+        for (i, c) in line.char_indices() {
+            match c {
+                '@' if robot => return Err(ParseError::at(input, &line[i..], "no second robot '@'")),
+                '@' => robot = true,
+                '[' if !line[i + 1..].starts_with(']') => return Err(ParseError::at(input, &line[i..], "box '[]'")),
+                ']' if !line[..i].ends_with('[') => return Err(ParseError::at(input, &line[i..], "box '[]'")),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let end = input
         .lines()
-        .take_while(|&line| line.len() > 0)
-        .collect();
-
-    let mut robot = (0, 0);
-    let items: Vec<Vec<Item>> = (1..lines.len()-1)
-        .map(|y| {
-            let line = lines[y].as_bytes();
-            (1..line.len()-1)
-                .map(|x|
-                    match line[x] {
-                        b'.' => Item::Empty,
-                        b'#' => Item::Wall,
-                        b'O' => Item::Box,
-                        b'@' => {
-                            robot = (x - 1, y - 1);
-                            Item::Empty
-                        },
-                        x => panic!("Invalid character in map '{x}'")
-                    })
-                .collect()
-        })
-        .collect();
-
-    Map::new(robot, items)
+        .find(|line| line.is_empty())
+        .map_or(input.len(), |line| line.as_ptr() as usize - input.as_ptr() as usize);
+    let tiles = Grid::parse(&input[..end], "map item", |c| match c {
+        '.' | '#' | 'O' | '[' | ']' | '@' => Some(c),
+        _ => None,
+    })?;
+    check_cells(input, &input[..end])?;
+
+    let robot = tiles
+        .position(|&c| c == '@')
+        .ok_or_else(|| ParseError::at(input, &input[end..], "robot '@'"))?;
+    let items = tiles.map(|&c| match c {
+        '#' => Item::Wall,
+        'O' => Item::Box,
+        '[' => Item::BoxLeft,
+        ']' => Item::BoxRight,
+        _ => Item::Empty,
+    });
+
+    Ok(Map::new(robot, items))
 }
 
-pub fn parse_input(input: &str) -> (Map, Vec<Direction>) {    
-    let map = parse_map(input);
-    let dirs = parse_directions(input);
-    (map, dirs)
+pub fn parse_input(input: &str) -> Result<(Map, Vec<Direction>), ParseError> {
+    let map = parse_map(input)?;
+    let dirs = parse_directions(input)?;
+    Ok((map, dirs))
 }
 
 #[cfg(test)]
 mod tests {
+    use grid::Point;
+
     use super::*;
 
     #[test]
@@ -63,17 +79,67 @@ mod tests {
 
 ^>
 v<";
-        let (map, dirs) = parse_input(input);
+        let (map, dirs) = parse_input(input).expect("Expecting successful parsing");
         
-        let expected_map_robot = (0, 1);
-        let expected_map_items = vec![
-            vec![Item::Empty, Item::Box],
-            vec![Item::Empty, Item::Empty],
-        ];
+        let expected_map_robot = Point::new(1, 2);
+        let expected_map_items = Grid::parse("\
+####
+#.O#
+#..#
+####", "map item", |c| match c {
+            '#' => Some(Item::Wall),
+            'O' => Some(Item::Box),
+            '.' => Some(Item::Empty),
+            _ => None,
+        }).expect("Expecting successful parsing");
         let expected_dirs = vec![Direction::Up, Direction::Right, Direction::Down, Direction::Left];
 
         assert_eq!(map.robot, expected_map_robot, "Robot position does not match expected");
         assert_eq!(map.items, expected_map_items, "Map items do not match expected");
         assert_eq!(dirs, expected_dirs, "Directions do not match expected");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let input = "\
+####
+#.O#
+#@.#
+####
+
+^>
+vx<";
+        let result = parse_input(input);
+        assert_eq!(result.err(), Some(ParseError::new(7, 2, "x<", "direction")));
+    }
+
+    #[test]
+    fn test_parse_second_robot() {
+        let input = "\
+####
+#@.#
+#.@#
+####
+
+<";
+        let result = parse_input(input);
+        assert_eq!(result.err(), Some(ParseError::new(3, 3, "@#", "no second robot '@'")));
+    }
+
+    #[test]
+    fn test_parse_half_box() {
+        let input = "\
+######
+#@[].#
+#.[..#
+######
+
+<";
+        let result = parse_input(input);
+        assert_eq!(result.err(), Some(ParseError::new(3, 3, "[..#", "box '[]'")));
+        let result = parse_input(&input.replace("#.[..#", "#..].#"));
+        assert_eq!(result.err(), Some(ParseError::new(3, 4, "].#", "box '[]'")));
+        let result = parse_input(&input.replace("#.[..#", "#[[]]#"));
+        assert_eq!(result.err(), Some(ParseError::new(3, 2, "[[]]#", "box '[]'")));
+    }
+}
@@ -0,0 +1,228 @@
+use std::{fs, io, path::Path};
+
+use grid::{Direction, Point};
+
+use crate::map::{Item, Map};
+
+/// Moves between two stored copies of the map
+const KEYFRAME_INTERVAL: usize = 1024;
+
+/// Cells a move changed and where it left the robot
+struct Delta {
+    robot: Point,
+    cells: Vec<(Point, Item)>,
+}
+
+impl Delta {
+    fn apply(&self, map: &mut Map) {
+        for (p, item) in &self.cells {
+            map.items[*p] = item.clone();
+        }
+        map.robot = self.robot;
+    }
+}
+
+/// Every state of the map while the robot follows its directions, stored as the
+/// cells each move changes plus a copy of the map every `KEYFRAME_INTERVAL` moves
+pub struct Replay {
+    dirs: Vec<Direction>,
+    interval: usize,
+    keyframes: Vec<Map>,
+    deltas: Vec<Delta>,
+}
+
+impl Replay {
+    /// Records the initial map followed by the changes of each move
+    pub fn record(map: &Map, dirs: &[Direction]) -> Self {
+        Self::record_every(map, dirs, KEYFRAME_INTERVAL)
+    }
+
+    fn record_every(map: &Map, dirs: &[Direction], interval: usize) -> Self {
+        let mut map = map.clone();
+        let mut keyframes = vec![map.clone()];
+        let mut deltas = Vec::with_capacity(dirs.len());
+        for (i, &dir) in dirs.iter().enumerate() {
+            let cells = map
+                .attempt_move(dir)
+                .into_iter()
+                .map(|p| (p, map.items[p].clone()))
+                .collect();
+            deltas.push(Delta { robot: map.robot, cells });
+            if (i + 1) % interval == 0 {
+                keyframes.push(map.clone());
+            }
+        }
+        Self { dirs: dirs.to_vec(), interval, keyframes, deltas }
+    }
+
+    /// Number of moves replayed
+    pub fn steps(&self) -> usize {
+        self.dirs.len()
+    }
+
+    /// Map after `step` moves, step 0 being the initial map, rebuilt from the closest keyframe
+    pub fn seek(&self, step: usize) -> Option<Map> {
+        if step > self.steps() {
+            return None;
+        }
+        let keyframe = step / self.interval;
+        let mut map = self.keyframes[keyframe].clone();
+        self.deltas[keyframe * self.interval..step]
+            .iter()
+            .for_each(|delta| delta.apply(&mut map));
+        Some(map)
+    }
+
+    /// Calls `f` with every frame in order, replaying the moves over a single map
+    fn for_each_frame(&self, mut f: impl FnMut(usize, &Map)) {
+        let mut map = self.keyframes[0].clone();
+        f(0, &map);
+        for (i, delta) in self.deltas.iter().enumerate() {
+            delta.apply(&mut map);
+            f(i + 1, &map);
+        }
+    }
+
+    fn title(&self, step: usize) -> String {
+        match step {
+            0 => "Initial state:".to_string(),
+            _ => format!("Move {} ({step}/{}):", self.dirs[step - 1].arrow(), self.steps()),
+        }
+    }
+
+    /// Every frame as text, titled with the move that produced it
+    pub fn text(&self) -> String {
+        let mut frames = Vec::with_capacity(self.steps() + 1);
+        self.for_each_frame(|step, map| frames.push(format!("{}\n{}", self.title(step), map.render())));
+        frames.join("\n")
+    }
+
+    /// Asciicast v2 recording showing a frame every `interval` seconds
+    pub fn asciicast(&self, interval: f64) -> String {
+        let map = &self.keyframes[0];
+        let mut cast = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}\n",
+            map.items.width().max(24),
+            map.items.height() + 1,
+        );
+        self.for_each_frame(|step, map| {
+            let screen = format!("{}\n{}", self.title(step), map.render()).replace('\n', "\\r\\n");
+            cast.push_str(&format!(
+                "[{:.3}, \"o\", \"\\u001b[H\\u001b[2J{screen}\"]\n",
+                step as f64 * interval,
+            ));
+        });
+        cast
+    }
+
+    /// Writes every frame as `frame-NNNNN.ppm` inside `dir`
+    pub fn write_ppm(&self, dir: &Path, scale: usize) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        let mut result = Ok(());
+        self.for_each_frame(|step, map| {
+            if result.is_ok() {
+                result = fs::write(dir.join(format!("frame-{step:05}.ppm")), map.ppm(scale));
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_input;
+
+    const INPUT: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_seek() {
+        let (map, dirs) = parse_input(INPUT).expect("Expecting successful parsing");
+        let replay = Replay::record(&map, &dirs);
+        assert_eq!(replay.steps(), 15);
+        assert_eq!(replay.seek(0).map(|m| m.render()), Some(map.render()));
+        assert_eq!(replay.seek(4).map(|m| m.render()).as_deref(), Some("\
+########
+#..@OO.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+"));
+        assert_eq!(replay.seek(15).map(|m| m.gps_score()), Some(2028));
+        assert!(replay.seek(16).is_none());
+    }
+
+    #[test]
+    fn test_seek_across_keyframes() {
+        let (map, dirs) = parse_input(INPUT).expect("Expecting successful parsing");
+        let map = map.widen();
+        let replay = Replay::record_every(&map, &dirs, 4);
+        assert_eq!(replay.keyframes.len(), 4);
+        let mut expected = map.clone();
+        for step in 0..=dirs.len() {
+            assert_eq!(replay.seek(step).map(|m| m.render()), Some(expected.render()), "Step {step}");
+            if let Some(&dir) = dirs.get(step) {
+                expected.attempt_move(dir);
+            }
+        }
+    }
+
+    #[test]
+    fn test_text_frames() {
+        let (map, dirs) = parse_input(INPUT).expect("Expecting successful parsing");
+        let text = Replay::record(&map, &dirs[..1]).text();
+        assert_eq!(text, "\
+Initial state:
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+Move < (1/1):
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+");
+    }
+
+    #[test]
+    fn test_asciicast_frames() {
+        let (map, dirs) = parse_input(INPUT).expect("Expecting successful parsing");
+        let cast = Replay::record(&map, &dirs).asciicast(0.5);
+        let lines: Vec<_> = cast.lines().collect();
+        assert_eq!(lines.len(), 17, "Header followed by a line per frame");
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 24, \"height\": 9}");
+        assert!(lines[2].starts_with("[0.500, \"o\", \"\\u001b[H\\u001b[2JMove < (1/15):\\r\\n########\\r\\n"));
+    }
+
+    #[test]
+    fn test_ppm_frame() {
+        let (map, _) = parse_input(INPUT).expect("Expecting successful parsing");
+        let image = map.ppm(2);
+        let header = b"P6\n16 16\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 16 * 16 * 3);
+    }
+}
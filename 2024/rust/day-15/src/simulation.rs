@@ -1,23 +1,40 @@
-use crate::{direction::Direction, map::Map};
+use aoc_core::{ParseError, Solution};
+use grid::Direction;
 
-pub fn part1(map: &mut Map, dirs: &[Direction]) -> usize {
-    dirs
-        .iter()
-        .for_each(|&dir| map.attempt_move(dir));
-    map.gps_score()
-}
+use crate::{map::Map, parser};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
 
-pub fn part2(map: &mut Map, dirs: &[Direction]) -> usize {
-    dirs
-        .iter()
-        .for_each(|&dir| map.attempt_move(dir));
-    map.gps_score()
+    type Input = (Map, Vec<Direction>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1((map, dirs): &Self::Input) -> usize {
+        let mut map = map.clone();
+        dirs
+            .iter()
+            .for_each(|&dir| { map.attempt_move(dir); });
+        map.gps_score()
+    }
+
+    fn part2((map, dirs): &Self::Input) -> usize {
+        let mut map = map.widen();
+        dirs
+            .iter()
+            .for_each(|&dir| { map.attempt_move(dir); });
+        map.gps_score()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{parser::parse_input};
 
     #[test]
     fn test_part1_small() {
@@ -32,8 +49,7 @@ mod tests {
 ########
 
 <^^>>>vv<v>>v<<";
-        let (mut map, dirs) = parse_input(input);
-        assert_eq!(part1(&mut map, &dirs), 2028);
+        assert_eq!(Day15::part1(&Day15::parse(input).expect("Expecting successful parsing")), 2028);
     }
 
     #[test]
@@ -60,12 +76,33 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-        let (mut map, dirs) = parse_input(input);
-        assert_eq!(part1(&mut map, &dirs), 10092);
+        assert_eq!(Day15::part1(&Day15::parse(input).expect("Expecting successful parsing")), 10092);
     }
 
-    // #[test]
-    // fn test_part2() {
-        
-    // }
-}
\ No newline at end of file
+    #[test]
+    fn test_part2() {
+        let input = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+        assert_eq!(Day15::part2(&Day15::parse(input).expect("Expecting successful parsing")), 9021);
+    }
+}
@@ -0,0 +1,99 @@
+/// (x, y) deltas of the eight compass directions, clockwise from up
+pub const COMPASS: [(isize, isize); 8] = [
+    ( 0, -1), // Up
+    ( 1, -1), // Up right
+    ( 1,  0), // Right
+    ( 1,  1), // Down right
+    ( 0,  1), // Down
+    (-1,  1), // Down left
+    (-1,  0), // Left
+    (-1, -1), // Up left
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// (x, y) delta of a single step
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up    => ( 0, -1),
+            Direction::Right => ( 1,  0),
+            Direction::Down  => ( 0,  1),
+            Direction::Left  => (-1,  0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up    => '^',
+            Direction::Right => '>',
+            Direction::Down  => 'v',
+            Direction::Left  => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            x => Err(x)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+    }
+
+    #[test]
+    fn arrows() {
+        for dir in Direction::ALL {
+            assert_eq!(Direction::try_from(dir.arrow()), Ok(dir));
+        }
+        assert_eq!(Direction::try_from('x'), Err('x'));
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::{Index, IndexMut};
+
+use aoc_core::{parser::grid_lines, ParseError};
+
+use crate::{direction::{Direction, COMPASS}, point::Point};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Parses one cell per character, rejecting empty input, rows of uneven width
+    /// and characters for which `cell` returns `None`
+    pub fn parse(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines = grid_lines(input)?;
+        let width = lines[0].chars().count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| ParseError::at(input, &line[i..], expected))?;
+                cells.push(value);
+            }
+        }
+        Ok(Self { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.contains(p) { Some(&self.cells[p.y * self.width + p.x]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) { Some(&mut self.cells[p.y * self.width + p.x]) } else { None }
+    }
+
+    /// Point displaced by (dx, dy), if it stays inside the grid
+    pub fn offset(&self, p: Point, dx: isize, dy: isize) -> Option<Point> {
+        p.offset(dx, dy).filter(|&q| self.contains(q))
+    }
+
+    /// Next point in `dir`, if it stays inside the grid
+    pub fn step(&self, p: Point, dir: Direction) -> Option<Point> {
+        p.step(dir).filter(|&q| self.contains(q))
+    }
+
+    /// Up, right, down and left neighbors inside the grid
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(p, dir))
+    }
+
+    /// Neighbors inside the grid in the eight compass directions, clockwise from up
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        COMPASS
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(p, dx, dy))
+    }
+
+    /// Points in row-major order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    /// Text with one character per cell and a line per row
+    pub fn render(&self, mut cell: impl FnMut(Point, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for (p, value) in self.iter() {
+            text.push(cell(p, value));
+            if p.x == self.width - 1 {
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// Binary PPM image with every cell drawn as a `scale`×`scale` square of its RGB color
+    pub fn ppm(&self, scale: usize, mut color: impl FnMut(Point, &T) -> [u8; 3]) -> Vec<u8> {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        image.reserve(width * height * 3);
+        let colors: Vec<[u8; 3]> = self.iter().map(|(p, value)| color(p, value)).collect();
+        for y in 0..height {
+            for x in 0..width {
+                image.extend_from_slice(&colors[y / scale * self.width + x / scale]);
+            }
+        }
+        image
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p).expect("Expected point inside the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p).expect("Expected point inside the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+123
+456
+";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(INPUT, "digit", |c| c.to_digit(10)).expect("Expecting successful parsing")
+    }
+
+    #[test]
+    fn parse_digits() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+    }
+
+    #[test]
+    fn parse_invalid_cell() {
+        let result = Grid::parse("123\n4x6\n", "digit", |c| c.to_digit(10));
+        assert_eq!(result, Err(ParseError::new(2, 2, "x6", "digit")));
+    }
+
+    #[test]
+    fn parse_uneven_rows() {
+        let result = Grid::parse("...\n..\n...\n", "tile", Some);
+        assert_eq!(result, Err(ParseError::new(2, 3, "", "row of width 3")));
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = digits();
+        let n4: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(n4, vec![Point::new(1, 0), Point::new(0, 1)]);
+        let n8: Vec<Point> = grid.neighbors8(Point::new(0, 0)).collect();
+        assert_eq!(n8, vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn render_back() {
+        let grid = digits();
+        let text = grid.render(|_, &d| char::from_digit(d, 10).expect("Expecting a digit"));
+        assert_eq!(text, INPUT);
+    }
+
+    #[test]
+    fn scaled_ppm() {
+        let grid = Grid::new(2, 1, 0);
+        let image = grid.ppm(2, |p, _| [p.x as u8; 3]);
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(&image[header.len()..], &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1].repeat(2)[..]);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::direction::Direction;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Point displaced by (dx, dy), if it does not fall off the top or left edge
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Self> {
+        let x = self.x.checked_add_signed(dx)?;
+        let y = self.y.checked_add_signed(dy)?;
+        Some(Self { x, y })
+    }
+
+    pub fn step(&self, dir: Direction) -> Option<Self> {
+        let (dx, dy) = dir.delta();
+        self.offset(dx, dy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_inside() {
+        let p = Point::new(1, 1);
+        assert_eq!(p.step(Direction::Up), Some(Point::new(1, 0)));
+        assert_eq!(p.step(Direction::Right), Some(Point::new(2, 1)));
+    }
+
+    #[test]
+    fn step_off_top_left() {
+        let p = Point::new(0, 0);
+        assert_eq!(p.step(Direction::Up), None);
+        assert_eq!(p.step(Direction::Left), None);
+    }
+}